@@ -11,6 +11,10 @@ pub const MIN_SQRT_PRICE: u128 = 15258932000000000000;
 pub const TICK_SEARCH_RANGE: i32 = 256;
 pub const CHUNK_SIZE: i32 = 64;
 
+pub const LIQUIDITY_TICK_LIMIT: u32 = 256;
+pub const TICKMAP_CHUNK_LIMIT: u16 = 64;
+pub const MAX_ORACLE_CARDINALITY: u32 = 65535;
+
 #[wasm_wrapper]
 pub fn get_global_max_sqrt_price() -> u128 {
     MAX_SQRT_PRICE
@@ -52,3 +56,18 @@ pub fn get_max_chunk(tick_spacing: u32) -> u32 {
 pub fn get_chunk_size() -> i32 {
     CHUNK_SIZE
 }
+
+#[wasm_wrapper]
+pub fn get_liquidity_tick_limit() -> u32 {
+    LIQUIDITY_TICK_LIMIT
+}
+
+#[wasm_wrapper]
+pub fn get_tickmap_chunk_limit() -> u16 {
+    TICKMAP_CHUNK_LIMIT
+}
+
+#[wasm_wrapper]
+pub fn get_max_oracle_cardinality() -> u32 {
+    MAX_ORACLE_CARDINALITY
+}
@@ -1,6 +1,7 @@
-use super::PoolKey;
-use crate::{liquidity::Liquidity, sqrt_price::SqrtPrice, token_amount::TokenAmount};
-use odra::prelude::vec::Vec;
+use super::{FeeTier, PauseStatus, PoolKey};
+use crate::{
+    liquidity::Liquidity, percentage::Percentage, sqrt_price::SqrtPrice, token_amount::TokenAmount,
+};
 use odra::Event;
 
 use serde::{Deserialize, Serialize};
@@ -46,8 +47,10 @@ pub struct CrossTickEvent {
     pub timestamp: u64,
     pub address: String,
     pub pool: PoolKey,
-    #[tsify(type = "bigint[]")]
-    pub indexes: Vec<i32>,
+    #[tsify(type = "bigint")]
+    pub index: i32,
+    pub sign: bool,
+    pub liquidity_change: Liquidity,
 }
 
 #[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
@@ -64,4 +67,158 @@ pub struct SwapEvent {
     pub start_sqrt_price: SqrtPrice,
     pub target_sqrt_price: SqrtPrice,
     pub x_to_y: bool,
+    pub by_amount_in: bool,
+    pub liquidity: Liquidity,
+    #[tsify(type = "bigint")]
+    pub current_tick_index: i32,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct FlashEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub receiver: String,
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+    pub fee_x: TokenAmount,
+    pub fee_y: TokenAmount,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct CreatePoolEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub pool: PoolKey,
+    pub init_sqrt_price: SqrtPrice,
+    #[tsify(type = "bigint")]
+    pub init_tick: i32,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ClaimFeeEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub owner: String,
+    #[tsify(type = "bigint")]
+    pub position_id: u64,
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct TransferPositionEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub receiver: String,
+    #[tsify(type = "bigint")]
+    pub position_id: u64,
+    pub pool: PoolKey,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct WithdrawProtocolFeeEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct AddFeeTierEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub fee_tier: FeeTier,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct RemoveFeeTierEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub fee_tier: FeeTier,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ChangeProtocolFeeEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub protocol_fee: Percentage,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ChangeFeeReceiverEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub pool: PoolKey,
+    pub fee_receiver: String,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct AdminProposedEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub admin: String,
+    pub pending_admin: String,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct AdminChangedEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub previous_admin: String,
+    pub admin: String,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ChangePauseStatusEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub pause_status: PauseStatus,
+}
+
+#[derive(Event, PartialEq, Eq, Debug, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct ChangePoolPauseStatusEvent {
+    #[tsify(type = "bigint")]
+    pub timestamp: u64,
+    pub address: String,
+    pub pool: PoolKey,
+    pub pause_status: PauseStatus,
 }
@@ -1,4 +1,5 @@
 use crate::percentage::Percentage;
+use crate::PauseStatus;
 use odra::OdraType;
 
 use serde::{Deserialize, Serialize};
@@ -9,5 +10,7 @@ use tsify::Tsify;
 #[serde(rename_all = "camelCase")]
 pub struct InvariantConfig {
     pub admin: String,
+    pub pending_admin: Option<String>,
     pub protocol_fee: Percentage,
+    pub pause_status: PauseStatus,
 }
@@ -2,6 +2,7 @@ pub mod errors;
 pub mod events;
 pub mod fee_tier;
 pub mod invariant_config;
+pub mod pause_status;
 pub mod pool;
 pub mod pool_key;
 pub mod position;
@@ -11,6 +12,7 @@ pub use errors::*;
 pub use events::*;
 pub use fee_tier::*;
 pub use invariant_config::*;
+pub use pause_status::*;
 pub use pool::*;
 pub use pool_key::*;
 pub use position::*;
@@ -0,0 +1,12 @@
+use odra::OdraType;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+
+#[derive(OdraType, Debug, Default, PartialEq, Eq, Copy, Serialize, Deserialize, Tsify)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseStatus {
+    pub swaps: bool,
+    pub positions: bool,
+}
@@ -1,5 +1,6 @@
 use crate::{
-    fee_growth::FeeGrowth, liquidity::Liquidity, sqrt_price::SqrtPrice, token_amount::TokenAmount,
+    fee_growth::FeeGrowth, liquidity::Liquidity, seconds_per_liquidity::SecondsPerLiquidity,
+    sqrt_price::SqrtPrice, token_amount::TokenAmount,
 };
 use odra::OdraType;
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,7 @@ pub struct Pool {
     pub current_tick_index: i32,
     pub fee_growth_global_x: FeeGrowth,
     pub fee_growth_global_y: FeeGrowth,
+    pub seconds_per_liquidity_global: SecondsPerLiquidity,
     pub fee_protocol_token_x: TokenAmount,
     pub fee_protocol_token_y: TokenAmount,
     #[tsify(type = "bigint")]
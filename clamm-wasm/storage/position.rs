@@ -9,6 +9,8 @@ use tsify::Tsify;
 #[tsify(into_wasm_abi, from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
+    #[tsify(type = "bigint")]
+    pub id: u64,
     pub pool_key: PoolKey,
     pub liquidity: Liquidity,
     #[tsify(type = "bigint")]
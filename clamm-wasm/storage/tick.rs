@@ -1,5 +1,6 @@
 use crate::fee_growth::FeeGrowth;
 use crate::liquidity::Liquidity;
+use crate::seconds_per_liquidity::SecondsPerLiquidity;
 use crate::sqrt_price::SqrtPrice;
 use decimal::*;
 use odra::types::{U128, U256};
@@ -22,6 +23,7 @@ pub struct Tick {
     pub fee_growth_outside_y: FeeGrowth,
     #[tsify(type = "bigint")]
     pub seconds_outside: u64,
+    pub seconds_per_liquidity_outside: SecondsPerLiquidity,
 }
 
 impl Default for Tick {
@@ -35,6 +37,7 @@ impl Default for Tick {
             fee_growth_outside_x: FeeGrowth::new(U128::from(0)),
             fee_growth_outside_y: FeeGrowth::new(U128::from(0)),
             seconds_outside: 0u64,
+            seconds_per_liquidity_outside: SecondsPerLiquidity::new(U128::from(0)),
         }
     }
 }
@@ -23,6 +23,7 @@ import type {
   Pool,
   PoolKey,
   Position,
+  SecondsPerLiquidity,
   SqrtPrice,
   Tick,
   TokenAmount
@@ -77,6 +78,12 @@ export const decodeAddress = (bytes: Uint8Array): [string, Uint8Array] => {
   const address = bytesToHex(unwrap(result, DecodeError.DecodingAddressFailed))
   return [address, remainder!]
 }
+export const decodeOptionalAddress = (bytes: Uint8Array): [string | null, Uint8Array] => {
+  if (bytes[0] === 0) {
+    return [null, bytes.slice(1, bytes.length)]
+  }
+  return decodeAddress(bytes.slice(1, bytes.length))
+}
 export const decodeString = (bytes: Uint8Array): [string, Uint8Array] => {
   const { result, remainder } = stringParser.fromBytesWithRemainder(bytes)
   const value = lowerCaseFirstLetter(unwrap(result, DecodeError.DecodingStringFailed))
@@ -97,88 +104,86 @@ export const decodeInvariantConfig = (rawBytes: string) => {
   const bytes = parseBytes(rawBytes)
   const structNameRemainder = decodeString(bytes)[1]
   const [admin, adminRemainder]: [string, Uint8Array] = decodeAddress(structNameRemainder)
-  const [protocolFee, remainder]: [Percentage, Uint8Array] = decodeDecimal(
+  const [pendingAdmin, pendingAdminRemainder]: [string | null, Uint8Array] =
+    decodeOptionalAddress(adminRemainder)
+  const [protocolFee, protocolFeeRemainder]: [Percentage, Uint8Array] = decodeDecimal(
     u128Parser,
-    adminRemainder,
+    pendingAdminRemainder,
     DecodeError.DecodingDecimalFailed
   )
+  const pauseStatusRemainder = decodeString(protocolFeeRemainder)[1]
+  const [swaps, swapsRemainder]: [boolean, Uint8Array] = decodeBool(pauseStatusRemainder)
+  const [positions, remainder]: [boolean, Uint8Array] = decodeBool(swapsRemainder)
 
   assertBytes(remainder)
 
   return {
     admin,
-    protocolFee: protocolFee
+    pendingAdmin,
+    protocolFee: protocolFee,
+    pauseStatus: {
+      swaps,
+      positions
+    }
   }
 }
 
-export const decodePoolKeys = (rawBytes: string): PoolKey[] => {
+export const decodePoolKey = (rawBytes: string): PoolKey => {
   const bytes = parseBytes(rawBytes)
-  const stringRemainder = decodeString(bytes)[1]
-
-  const result = decodeBigint(u32Parser, stringRemainder)
-  const poolKeyCount = result[0]
-  let remainingBytes = result[1]
-  const poolKeys = []
-  for (let i = 0; i < poolKeyCount; i++) {
-    remainingBytes = decodeString(remainingBytes)[1]
-    const [tokenX, tokenXRemainder]: [string, Uint8Array] = decodeAddress(remainingBytes)
-    remainingBytes = tokenXRemainder
-    const [tokenY, tokenYRemainder]: [string, Uint8Array] = decodeAddress(remainingBytes)
-    remainingBytes = tokenYRemainder
-    remainingBytes = decodeString(remainingBytes)[1]
-    const [fee, feeRemainder]: [Percentage, Uint8Array] = decodeDecimal(
-      u128Parser,
-      remainingBytes,
-      DecodeError.DecodingDecimalFailed
-    )
-    remainingBytes = feeRemainder
-    const [tickSpacing, remainder]: [bigint, Uint8Array] = decodeBigint(
-      u32Parser,
-      remainingBytes,
-      DecodeError.DecodingU32Failed
-    )
-    remainingBytes = remainder
-    poolKeys.push({
-      tokenX,
-      tokenY,
-      feeTier: { fee, tickSpacing }
-    })
+  const remainingBytes = decodeOption(bytes)
+  const [tokenX, tokenXRemainder]: [string, Uint8Array] = decodeAddress(remainingBytes)
+  const [tokenY, tokenYRemainder]: [string, Uint8Array] = decodeAddress(tokenXRemainder)
+  const feeTierRemainder = decodeString(tokenYRemainder)[1]
+  const [fee, feeRemainder]: [Percentage, Uint8Array] = decodeDecimal(
+    u128Parser,
+    feeTierRemainder,
+    DecodeError.DecodingDecimalFailed
+  )
+  const [tickSpacing, remainder]: [bigint, Uint8Array] = decodeBigint(
+    u32Parser,
+    feeRemainder,
+    DecodeError.DecodingU32Failed
+  )
+
+  assertBytes(remainder)
+
+  return {
+    tokenX,
+    tokenY,
+    feeTier: { fee, tickSpacing }
   }
+}
 
-  assertBytes(remainingBytes)
+export const decodeFeeTier = (rawBytes: string): FeeTier => {
+  const bytes = parseBytes(rawBytes)
+  const remainingBytes = decodeOption(bytes)
+  const [fee, feeRemainder]: [Percentage, Uint8Array] = decodeDecimal(
+    u128Parser,
+    remainingBytes,
+    DecodeError.DecodingDecimalFailed
+  )
+  const [tickSpacing, remainder]: [bigint, Uint8Array] = decodeBigint(
+    u32Parser,
+    feeRemainder,
+    DecodeError.DecodingU32Failed
+  )
 
-  return poolKeys
+  assertBytes(remainder)
+
+  return { fee, tickSpacing }
 }
-export const decodeFeeTiers = (rawBytes: string): FeeTier[] => {
+
+export const decodeLength = (rawBytes: string): bigint => {
   const bytes = parseBytes(rawBytes)
-  const stringRemainder = decodeString(bytes)[1]
-
-  const result = decodeBigint(u32Parser, stringRemainder)
-  const feeTierCount = result[0]
-  let remainingBytes = result[1]
-  const feeTiers: FeeTier[] = []
-
-  for (let i = 0; i < feeTierCount; i++) {
-    remainingBytes = decodeString(remainingBytes)[1]
-    const [fee, feeRemainder]: [Percentage, Uint8Array] = decodeDecimal(
-      u128Parser,
-      remainingBytes,
-      DecodeError.DecodingDecimalFailed
-    )
-    remainingBytes = feeRemainder
-    const [tickSpacing, remainder]: [bigint, Uint8Array] = decodeBigint(
-      u32Parser,
-      remainingBytes,
-      DecodeError.DecodingU32Failed
-    )
-    remainingBytes = remainder
-    const feeTier = { fee, tickSpacing }
-    feeTiers.push(feeTier)
-  }
+  const [length, remainder]: [bigint, Uint8Array] = decodeBigint(
+    u32Parser,
+    bytes,
+    DecodeError.DecodingU32Failed
+  )
 
-  assertBytes(remainingBytes)
+  assertBytes(remainder)
 
-  return feeTiers
+  return length
 }
 export const decodePool = (rawBytes: string): Pool => {
   const bytes = parseBytes(rawBytes)
@@ -208,9 +213,13 @@ export const decodePool = (rawBytes: string): Pool => {
     feeGrowthGlobalXRemainder,
     DecodeError.DecodingDecimalFailed
   )
+  const [secondsPerLiquidityGlobal, secondsPerLiquidityGlobalRemainder]: [
+    SecondsPerLiquidity,
+    Uint8Array
+  ] = decodeDecimal(u128Parser, feeGrowthGlobalYRemainder, DecodeError.DecodingDecimalFailed)
   const [feeProtocolTokenX, feeProtocolTokenXRemainder]: [TokenAmount, Uint8Array] = decodeDecimal(
     u256Parser,
-    feeGrowthGlobalYRemainder,
+    secondsPerLiquidityGlobalRemainder,
     DecodeError.DecodingDecimalFailed
   )
   const [feeProtocolTokenY, feeProtocolTokenYRemainder]: [TokenAmount, Uint8Array] = decodeDecimal(
@@ -237,6 +246,7 @@ export const decodePool = (rawBytes: string): Pool => {
     currentTickIndex,
     feeGrowthGlobalX,
     feeGrowthGlobalY,
+    secondsPerLiquidityGlobal,
     feeProtocolTokenX,
     feeProtocolTokenY,
     startTimestamp,
@@ -248,7 +258,12 @@ export const decodePool = (rawBytes: string): Pool => {
 export const decodePosition = (rawBytes: string): Position => {
   const bytes = parseBytes(rawBytes)
   const remainingBytes = decodeOption(bytes)
-  const poolKeyRemainder = decodeString(remainingBytes)[1]
+  const [id, idRemainder]: [bigint, Uint8Array] = decodeBigint(
+    u64Parser,
+    remainingBytes,
+    DecodeError.DecodingU64Failed
+  )
+  const poolKeyRemainder = decodeString(idRemainder)[1]
   const [tokenX, tokenXRemainder]: [string, Uint8Array] = decodeAddress(poolKeyRemainder)
   const [tokenY, tokenYRemainder]: [string, Uint8Array] = decodeAddress(tokenXRemainder)
   const feeTierRemainder = decodeString(tokenYRemainder)[1]
@@ -307,6 +322,7 @@ export const decodePosition = (rawBytes: string): Position => {
   assertBytes(remainder)
 
   return {
+    id,
     poolKey: {
       tokenX,
       tokenY,
@@ -360,11 +376,13 @@ export const decodeTick = (rawBytes: string): Tick => {
     feeGrowthOutsideXRemainder,
     DecodeError.DecodingDecimalFailed
   )
-  const [secondsOutside, remainder]: [bigint, Uint8Array] = decodeBigint(
+  const [secondsOutside, secondsOutsideRemainder]: [bigint, Uint8Array] = decodeBigint(
     u64Parser,
     feeGrowthOutsideYRemainder,
     DecodeError.DecodingU64Failed
   )
+  const [secondsPerLiquidityOutside, remainder]: [SecondsPerLiquidity, Uint8Array] =
+    decodeDecimal(u128Parser, secondsOutsideRemainder, DecodeError.DecodingDecimalFailed)
 
   assertBytes(remainder)
 
@@ -376,7 +394,8 @@ export const decodeTick = (rawBytes: string): Tick => {
     sqrtPrice,
     feeGrowthOutsideX,
     feeGrowthOutsideY,
-    secondsOutside
+    secondsOutside,
+    secondsPerLiquidityOutside
   }
 }
 
@@ -393,6 +412,19 @@ export const decodeChunk = (rawBytes: string): bigint => {
   return chunk
 }
 
+export const decodePositionId = (rawBytes: string): bigint => {
+  const bytes = parseBytes(rawBytes)
+  const [id, remainder]: [bigint, Uint8Array] = decodeBigint(
+    u64Parser,
+    bytes,
+    DecodeError.DecodingU64Failed
+  )
+
+  assertBytes(remainder)
+
+  return id
+}
+
 export const decodePositionLength = (rawBytes: string): bigint => {
   const bytes = parseBytes(rawBytes)
   const [length, remainder]: [bigint, Uint8Array] = decodeBigint(
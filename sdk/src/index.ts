@@ -1,9 +1,15 @@
 export type {
+  AddFeeTierEvent,
+  ChangeFeeReceiverEvent,
+  ChangeProtocolFeeEvent,
+  ClaimFeeEvent,
+  CreatePoolEvent,
   CreatePositionEvent,
   CrossTickEvent,
   FeeGrowth,
   FeeTier,
   FixedPoint,
+  FlashEvent,
   InvariantConfig,
   InvariantError,
   Liquidity,
@@ -13,13 +19,16 @@ export type {
   Position,
   Price,
   QuoteResult,
+  RemoveFeeTierEvent,
   RemovePositionEvent,
   SecondsPerLiquidity,
   SqrtPrice,
   SwapEvent,
   SwapResult,
   Tick,
-  TokenAmount
+  TokenAmount,
+  TransferPositionEvent,
+  WithdrawProtocolFeeEvent
 } from 'wasm'
 export { ALICE, BOB, DEFAULT_PAYMENT_AMOUNT, LOCAL_NODE_URL } from './consts'
 export { Erc20 } from './erc20'
@@ -23,11 +23,13 @@ import type {
 import { DEFAULT_PAYMENT_AMOUNT, INVARIANT_CONTRACT_NAME } from './consts'
 import {
   decodeChunk,
-  decodeFeeTiers,
+  decodeFeeTier,
   decodeInvariantConfig,
+  decodeLength,
   decodePool,
-  decodePoolKeys,
+  decodePoolKey,
   decodePosition,
+  decodePositionId,
   decodePositionLength,
   decodeTick
 } from './decoder'
@@ -263,8 +265,35 @@ export class Invariant {
     return decodeInvariantConfig(rawBytes)
   }
 
-  async getFeeTiers(): Promise<FeeTier[]> {
-    const key = hash('fee_tiers')
+  async getFeeTiersCount(): Promise<bigint> {
+    const buffor: number[] = []
+    buffor.push(...encodeString('fee_tiers'))
+    buffor.push(...encodeString('#'))
+    buffor.push(...encodeString('fee_tiers_length'))
+
+    const key = hash(new Uint8Array(buffor))
+    const stateRootHash = await this.client.nodeClient.getStateRootHash()
+    const response = await this.client.nodeClient.getDictionaryItemByName(
+      stateRootHash,
+      this.contract.contractHash!,
+      'state',
+      key,
+      { rawData: true }
+    )
+
+    const rawBytes = (response.CLValue! as any).bytes
+    return decodeLength(rawBytes)
+  }
+
+  async getFeeTier(index: bigint): Promise<FeeTier> {
+    const buffor: number[] = []
+    const indexBytes = bigintToByteArray(index)
+    buffor.push(...encodeString('fee_tiers'))
+    buffor.push(...encodeString('#'))
+    buffor.push(...encodeString('fee_tiers'))
+    buffor.push(...indexBytes.concat(Array(4 - indexBytes.length).fill(0)))
+
+    const key = hash(new Uint8Array(buffor))
     const stateRootHash = await this.client.nodeClient.getStateRootHash()
     const response = await this.client.nodeClient.getDictionaryItemByName(
       stateRootHash,
@@ -275,8 +304,18 @@ export class Invariant {
     )
 
     const rawBytes = (response.CLValue! as any).bytes
+    return decodeFeeTier(rawBytes)
+  }
 
-    return decodeFeeTiers(rawBytes)
+  async getFeeTiers(): Promise<FeeTier[]> {
+    const feeTiersCount = await this.getFeeTiersCount()
+    const feeTiers = await Promise.all(
+      Array.from(
+        { length: integerSafeCast(feeTiersCount) },
+        async (_, i) => await this.getFeeTier(BigInt(i))
+      )
+    )
+    return feeTiers
   }
 
   async feeTierExist(feeTier: FeeTier): Promise<boolean> {
@@ -345,7 +384,7 @@ export class Invariant {
     )
   }
 
-  async removePosition(signer: Keys.AsymmetricKey, index: bigint) {
+  async removePosition(signer: Keys.AsymmetricKey, id: bigint) {
     return await sendTx(
       this.contract,
       this.client.nodeClient,
@@ -354,14 +393,14 @@ export class Invariant {
       this.network,
       'remove_position',
       {
-        index: CLValueBuilder.u32(integerSafeCast(index))
+        id: CLValueBuilder.u64(BigNumber.from(id))
       }
     )
   }
 
   async transferPosition(
     signer: Keys.AsymmetricKey,
-    index: bigint,
+    id: bigint,
     receiverHash: Key,
     receiver: string
   ) {
@@ -376,13 +415,13 @@ export class Invariant {
       this.network,
       'transfer_position',
       {
-        index: CLValueBuilder.u32(integerSafeCast(index)),
+        id: CLValueBuilder.u64(BigNumber.from(id)),
         receiver: receiverKey
       }
     )
   }
 
-  async claimFee(signer: Keys.AsymmetricKey, index: bigint) {
+  async claimFee(signer: Keys.AsymmetricKey, id: bigint) {
     return await sendTx(
       this.contract,
       this.client.nodeClient,
@@ -391,21 +430,19 @@ export class Invariant {
       this.network,
       'claim_fee',
       {
-        index: CLValueBuilder.u32(integerSafeCast(index))
+        id: CLValueBuilder.u64(BigNumber.from(id))
       }
     )
   }
 
-  async getPosition(signer: Keys.AsymmetricKey, index: bigint): Promise<Position> {
+  async getPosition(id: bigint): Promise<Position> {
     const stateRootHash = await this.client.nodeClient.getStateRootHash()
     const buffor: number[] = []
-    const indexBytes = bigintToByteArray(index)
+    const idBytes = bigintToByteArray(id)
     buffor.push(...encodeString('positions'))
     buffor.push(...encodeString('#'))
     buffor.push(...encodeString('positions'))
-    buffor.push(...[Key.Account])
-    buffor.push(...signer.accountHash())
-    buffor.push(...indexBytes.concat(Array(4 - indexBytes.length).fill(0)))
+    buffor.push(...idBytes.concat(Array(8 - idBytes.length).fill(0)))
 
     const key = hash(new Uint8Array(buffor))
 
@@ -501,14 +538,45 @@ export class Invariant {
     return decodePositionLength(rawBytes)
   }
 
-  async getPositions(account: Keys.AsymmetricKey): Promise<Position[]> {
+  async getPositionId(account: Keys.AsymmetricKey, index: bigint): Promise<bigint> {
+    const stateRootHash = await this.client.nodeClient.getStateRootHash()
+    const buffor: number[] = []
+    const indexBytes = bigintToByteArray(index)
+    buffor.push(...encodeString('positions'))
+    buffor.push(...encodeString('#'))
+    buffor.push(...encodeString('position_ids'))
+    buffor.push(...[Key.Account])
+    buffor.push(...account.accountHash())
+    buffor.push(...indexBytes.concat(Array(4 - indexBytes.length).fill(0)))
+
+    const key = hash(new Uint8Array(buffor))
+
+    const response = await this.client.nodeClient.getDictionaryItemByName(
+      stateRootHash,
+      this.contract.contractHash!,
+      'state',
+      key,
+      { rawData: true }
+    )
+
+    const rawBytes = (response.CLValue! as any).bytes
+    return decodePositionId(rawBytes)
+  }
+
+  async getPositionIds(account: Keys.AsymmetricKey): Promise<bigint[]> {
     const positionsCount = await this.getPositionsCount(account)
-    const positions = await Promise.all(
+    const ids = await Promise.all(
       Array.from(
         { length: integerSafeCast(positionsCount) },
-        async (_, i) => await this.getPosition(account, BigInt(i))
+        async (_, i) => await this.getPositionId(account, BigInt(i))
       )
     )
+    return ids
+  }
+
+  async getPositions(account: Keys.AsymmetricKey): Promise<Position[]> {
+    const ids = await this.getPositionIds(account)
+    const positions = await Promise.all(ids.map(async id => await this.getPosition(id)))
     return positions
   }
 
@@ -563,8 +631,35 @@ export class Invariant {
     )
   }
 
-  private async getPoolKeys(): Promise<PoolKey[]> {
-    const key = hash('pool_keys')
+  async getPoolKeysCount(): Promise<bigint> {
+    const buffor: number[] = []
+    buffor.push(...encodeString('pool_keys'))
+    buffor.push(...encodeString('#'))
+    buffor.push(...encodeString('pool_keys_length'))
+
+    const key = hash(new Uint8Array(buffor))
+    const stateRootHash = await this.client.nodeClient.getStateRootHash()
+    const response = await this.client.nodeClient.getDictionaryItemByName(
+      stateRootHash,
+      this.contract.contractHash!,
+      'state',
+      key,
+      { rawData: true }
+    )
+
+    const rawBytes = (response.CLValue! as any).bytes
+    return decodeLength(rawBytes)
+  }
+
+  async getPoolKey(index: bigint): Promise<PoolKey> {
+    const buffor: number[] = []
+    const indexBytes = bigintToByteArray(index)
+    buffor.push(...encodeString('pool_keys'))
+    buffor.push(...encodeString('#'))
+    buffor.push(...encodeString('pool_keys'))
+    buffor.push(...indexBytes.concat(Array(4 - indexBytes.length).fill(0)))
+
+    const key = hash(new Uint8Array(buffor))
     const stateRootHash = await this.client.nodeClient.getStateRootHash()
     const response = await this.client.nodeClient.getDictionaryItemByName(
       stateRootHash,
@@ -575,7 +670,18 @@ export class Invariant {
     )
 
     const rawBytes = (response.CLValue! as any).bytes
-    return decodePoolKeys(rawBytes)
+    return decodePoolKey(rawBytes)
+  }
+
+  async getPoolKeys(): Promise<PoolKey[]> {
+    const poolKeysCount = await this.getPoolKeysCount()
+    const poolKeys = await Promise.all(
+      Array.from(
+        { length: integerSafeCast(poolKeysCount) },
+        async (_, i) => await this.getPoolKey(BigInt(i))
+      )
+    )
+    return poolKeys
   }
 
   async isTickInitialized(poolKey: PoolKey, tickIndex: bigint): Promise<boolean> {
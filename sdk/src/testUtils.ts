@@ -103,6 +103,7 @@ export const assertThrowsAsync = async (fn: Promise<any>, word?: InvariantError)
 
 export const positionEquals = async (position: Position, expectedPosition: Position) => {
   const chai = await loadChai()
+  chai.assert.equal(position.id, expectedPosition.id)
   chai.assert.equal(position.poolKey.tokenX, expectedPosition.poolKey.tokenX)
   chai.assert.equal(position.poolKey.tokenY, expectedPosition.poolKey.tokenY)
   chai.assert.equal(position.poolKey.feeTier.fee.v, expectedPosition.poolKey.feeTier.fee.v)
@@ -235,7 +235,7 @@ describe('sdk guide snippets', () => {
 
     // query state
     const pool: Pool = await invariant.getPool(poolKey)
-    const position: Position = await invariant.getPosition(account, 0n)
+    const position: Position = await invariant.getPosition(0n)
     const lowerTick: Tick = await invariant.getTick(poolKey, lowerTickIndex)
     const upperTick: Tick = await invariant.getTick(poolKey, upperTickIndex)
 
@@ -260,16 +260,16 @@ describe('sdk guide snippets', () => {
     const accountBalanceAfterClaim = await erc20.getBalanceOf(Key.Account, accountAddress)
     console.log(accountBalanceAfterClaim)
 
-    const positionToTransfer = await invariant.getPosition(account, 0n)
+    const positionToTransfer = await invariant.getPosition(positionId)
 
     // transfer position from one account to another
-    await invariant.transferPosition(account, 0n, Key.Account, receiverAddress)
+    await invariant.transferPosition(account, positionId, Key.Account, receiverAddress)
 
-    // get received position
-    const receiverPosition = await invariant.getPosition(receiver, 0n)
+    // get received position, its id stays the same after the transfer
+    const receiverPosition = await invariant.getPosition(positionId)
 
     // ###
-    await invariant.transferPosition(receiver, 0n, Key.Account, accountAddress)
+    await invariant.transferPosition(receiver, positionId, Key.Account, accountAddress)
     // ###
 
     // fetch user balances before removal
@@ -279,7 +279,7 @@ describe('sdk guide snippets', () => {
     console.log(accountToken0BalanceBeforeRemove, accountToken1BalanceBeforeRemove)
 
     // remove position
-    const removePositionResult = await invariant.removePosition(account, 0n)
+    const removePositionResult = await invariant.removePosition(account, positionId)
     console.log(removePositionResult.execution_results[0].result)
 
     // fetch user balances after removal
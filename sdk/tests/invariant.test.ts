@@ -126,7 +126,8 @@ describe('invariant test', async () => {
       sqrtPrice: { v: 999500149965000000000000n },
       feeGrowthOutsideX: { v: 0n },
       feeGrowthOutsideY: { v: 0n },
-      secondsOutside: lowerTick.secondsOutside
+      secondsOutside: lowerTick.secondsOutside,
+      secondsPerLiquidityOutside: lowerTick.secondsPerLiquidityOutside
     })
     chai.assert.deepEqual(upperTick, {
       index: 10n,
@@ -136,7 +137,8 @@ describe('invariant test', async () => {
       sqrtPrice: { v: 1000500100010000000000000n },
       feeGrowthOutsideX: { v: 0n },
       feeGrowthOutsideY: { v: 0n },
-      secondsOutside: upperTick.secondsOutside
+      secondsOutside: upperTick.secondsOutside,
+      secondsPerLiquidityOutside: upperTick.secondsPerLiquidityOutside
     })
   })
   it('create pool', async () => {
@@ -163,6 +165,7 @@ describe('invariant test', async () => {
       currentTickIndex: 0n,
       feeGrowthGlobalX: { v: 0n },
       feeGrowthGlobalY: { v: 0n },
+      secondsPerLiquidityGlobal: { v: 0n },
       feeProtocolTokenX: { v: 0n },
       feeProtocolTokenY: { v: 0n },
       startTimestamp: pool.startTimestamp,
@@ -216,6 +219,7 @@ describe('invariant test', async () => {
       currentTickIndex: 0n,
       feeGrowthGlobalX: { v: 0n },
       feeGrowthGlobalY: { v: 0n },
+      secondsPerLiquidityGlobal: { v: 0n },
       feeProtocolTokenX: { v: 0n },
       feeProtocolTokenY: { v: 0n },
       startTimestamp: pool.startTimestamp,
@@ -83,8 +83,9 @@ describe('test get liquidity by x', () => {
         pool.sqrtPrice
       )
 
-      const position = await invariant.getPosition(positionOwner, 0n)
+      const position = await invariant.getPosition(0n)
       const expectedPosition: Position = {
+        id: 0n,
         poolKey,
         liquidity: l,
         lowerTickIndex,
@@ -128,8 +129,9 @@ describe('test get liquidity by x', () => {
         pool.sqrtPrice
       )
 
-      const position = await invariant.getPosition(positionOwner, 1n)
+      const position = await invariant.getPosition(1n)
       const expectedPosition: Position = {
+        id: 1n,
         poolKey,
         liquidity: l,
         lowerTickIndex,
@@ -203,8 +205,9 @@ describe('test get liquidity by y', () => {
         pool.sqrtPrice
       )
 
-      const position = await invariant.getPosition(positionOwner, 0n)
+      const position = await invariant.getPosition(0n)
       const expectedPosition: Position = {
+        id: 0n,
         poolKey,
         liquidity: l,
         lowerTickIndex,
@@ -249,8 +252,9 @@ describe('test get liquidity by y', () => {
         pool.sqrtPrice
       )
 
-      const position = await invariant.getPosition(positionOwner, 1n)
+      const position = await invariant.getPosition(1n)
       const expectedPosition: Position = {
+        id: 1n,
         poolKey,
         liquidity: l,
         lowerTickIndex,
@@ -65,8 +65,9 @@ describe('position', () => {
   })
 
   it('create position', async () => {
-    const position = await invariant.getPosition(deployer, 0n)
+    const position = await invariant.getPosition(0n)
 
+    chai.assert.deepEqual(position.id, 0n)
     chai.assert.deepEqual(position.liquidity, { v: 100000000000n })
     chai.assert.deepEqual(position.lowerTickIndex, lowerTickIndex)
     chai.assert.deepEqual(position.upperTickIndex, upperTickIndex)
@@ -79,7 +80,7 @@ describe('position', () => {
   it('remove position', async () => {
     await invariant.removePosition(deployer, 0n)
 
-    assertThrowsAsync(invariant.getPosition(deployer, 0n))
+    assertThrowsAsync(invariant.getPosition(0n))
     const positions = await invariant.getPositions(deployer)
     chai.expect(positions.length).to.equal(0)
 
@@ -93,16 +94,46 @@ describe('position', () => {
     chai.expect(isUpperTickInitialized).to.equal(false)
   })
 
+  it('position ids', async () => {
+    await invariant.createPosition(
+      deployer,
+      poolKey,
+      lowerTickIndex,
+      upperTickIndex,
+      { v: 100000000000n },
+      { v: 1000000000000000000000000n },
+      { v: 1000000000000000000000000n }
+    )
+
+    const positionIds = await invariant.getPositionIds(deployer)
+    chai.assert.deepEqual(positionIds, [0n, 1n])
+
+    await invariant.removePosition(deployer, 0n)
+
+    const remainingPositionIds = await invariant.getPositionIds(deployer)
+    chai.assert.deepEqual(remainingPositionIds, [1n])
+
+    assertThrowsAsync(invariant.getPosition(0n))
+    const positions = await invariant.getPositions(deployer)
+    chai.expect(positions.length).to.equal(1)
+    chai.assert.equal(positions[0].id, 1n)
+  })
+
   it('transfer position', async () => {
     const recipient = BOB
     const recipientAddress = getAccountHashFromKey(recipient)
 
     await invariant.transferPosition(deployer, 0n, Key.Account, recipientAddress)
 
-    assertThrowsAsync(invariant.getPosition(deployer, 0n))
-    const position = await invariant.getPosition(recipient, 0n)
+    const deployerPositionIds = await invariant.getPositionIds(deployer)
+    chai.assert.deepEqual(deployerPositionIds, [])
+    const recipientPositionIds = await invariant.getPositionIds(recipient)
+    chai.assert.deepEqual(recipientPositionIds, [0n])
+
+    const position = await invariant.getPosition(0n)
 
     const expectedPosition: Position = {
+      id: 0n,
       poolKey,
       liquidity: { v: 100000000000n },
       lowerTickIndex,
@@ -168,7 +199,7 @@ describe('position', () => {
     const positionOwnerAfterX = await erc20.getBalanceOf(Key.Account, positionOwnerAddress)
     const invariantAfterX = await erc20.getBalanceOf(Key.Hash, hashes.invariant.packageHash)
 
-    const position = await invariant.getPosition(deployer, 0n)
+    const position = await invariant.getPosition(0n)
     const pool = await invariant.getPool(poolKey)
     const expectedTokensClaimed = 5n
 
@@ -220,7 +220,7 @@ describe('utils', () => {
 
       await invariant.swap(deployer, poolKey, true, swapAmount, true, targetSqrtPrice)
       const pool = await invariant.getPool(poolKey)
-      const position = await invariant.getPosition(deployer, 0n)
+      const position = await invariant.getPosition(0n)
       const lowerTick = await invariant.getTick(poolKey, lowerTickIndex)
       const upperTick = await invariant.getTick(poolKey, upperTickIndex)
 
@@ -0,0 +1,27 @@
+use odra::prelude::vec::Vec;
+use odra::types::U256;
+
+#[odra::external_contract]
+pub trait FlashCallback {
+    // called after the loan is sent, the receiver has to approve `amount + fee` of each token to the invariant before returning
+    fn invariant_flash_callback(
+        &mut self,
+        amount_x: U256,
+        amount_y: U256,
+        fee_x: U256,
+        fee_y: U256,
+        data: Vec<u8>,
+    );
+}
+
+#[odra::external_contract]
+pub trait SwapCallback {
+    // called after `amount_out` is sent, the caller has to approve `amount_in` of the input token to the invariant before returning
+    fn invariant_swap_callback(
+        &mut self,
+        x_to_y: bool,
+        amount_in: U256,
+        amount_out: U256,
+        data: Vec<u8>,
+    );
+}
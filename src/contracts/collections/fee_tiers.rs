@@ -1,44 +1,79 @@
 use crate::contracts::errors::InvariantError;
 use crate::contracts::FeeTier;
 use odra::prelude::vec::Vec;
-use odra::OdraType;
+use odra::{Mapping, Variable};
 
-#[derive(OdraType, Default)]
+#[odra::module]
 pub struct FeeTiers {
-    pub fee_tiers: Vec<FeeTier>,
+    fee_tiers: Mapping<u32, Option<FeeTier>>,
+    fee_tier_indexes: Mapping<FeeTier, Option<u32>>,
+    fee_tiers_length: Variable<u32>,
 }
 
+#[odra::module]
 impl FeeTiers {
     pub fn add(&mut self, fee_tier: FeeTier) -> Result<(), InvariantError> {
         if self.contains(fee_tier) {
             return Err(InvariantError::FeeTierAlreadyExist);
         }
 
-        self.fee_tiers.push(fee_tier);
+        let length = self.count();
+
+        self.fee_tiers.set(&length, Some(fee_tier));
+        self.fee_tier_indexes.set(&fee_tier, Some(length));
+        self.fee_tiers_length.set(length + 1);
         Ok(())
     }
 
     pub fn remove(&mut self, fee_tier: FeeTier) -> Result<(), InvariantError> {
         let index = self
-            .fee_tiers
-            .iter()
-            .position(|vec_fee_tier| *vec_fee_tier == fee_tier)
+            .fee_tier_indexes
+            .get(&fee_tier)
+            .flatten()
             .ok_or(InvariantError::FeeTierNotFound)?;
+        let last_index = self.count() - 1;
+
+        // following elements are shifted down, so the list keeps the insertion order
+        for current_index in index..last_index {
+            let next_fee_tier = self
+                .fee_tiers
+                .get(&(current_index + 1))
+                .flatten()
+                .ok_or(InvariantError::FeeTierNotFound)?;
+
+            self.fee_tiers.set(&current_index, Some(next_fee_tier));
+            self.fee_tier_indexes
+                .set(&next_fee_tier, Some(current_index));
+        }
 
-        self.fee_tiers.remove(index);
+        self.fee_tiers.set(&last_index, None);
+        self.fee_tier_indexes.set(&fee_tier, None);
+        self.fee_tiers_length.set(last_index);
         Ok(())
     }
 
     pub fn contains(&self, fee_tier: FeeTier) -> bool {
-        self.fee_tiers.contains(&fee_tier)
+        self.fee_tier_indexes.get(&fee_tier).flatten().is_some()
+    }
+
+    pub fn get(&self, offset: u32, limit: u32) -> Vec<FeeTier> {
+        let end = self.count().min(offset.saturating_add(limit));
+
+        (offset..end)
+            .flat_map(|index| self.fee_tiers.get(&index).flatten())
+            .collect()
     }
 
     pub fn get_all(&self) -> Vec<FeeTier> {
-        self.fee_tiers.clone()
+        self.get(0, self.count())
+    }
+
+    pub fn count(&self) -> u32 {
+        self.fee_tiers_length.get_or_default()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "casper")))]
 mod tests {
     use super::*;
     use crate::math::percentage::Percentage;
@@ -48,7 +83,7 @@ mod tests {
 
     #[test]
     fn test_add() {
-        let fee_tier_keys = &mut FeeTiers::default();
+        let fee_tier_keys = &mut FeeTiersDeployer::default();
         let fee_tier_key = FeeTier::default();
         let new_fee_tier_key = FeeTier::new(Percentage::new(U128::from(0)), 2).unwrap();
 
@@ -62,7 +97,7 @@ mod tests {
 
     #[test]
     fn test_remove() {
-        let fee_tier_keys = &mut FeeTiers::default();
+        let fee_tier_keys = &mut FeeTiersDeployer::default();
         let fee_tier_key = FeeTier::default();
 
         fee_tier_keys.add(fee_tier_key).unwrap();
@@ -76,7 +111,7 @@ mod tests {
 
     #[test]
     fn test_get_all() {
-        let fee_tier_keys = &mut FeeTiers::default();
+        let fee_tier_keys = &mut FeeTiersDeployer::default();
         let fee_tier_key = FeeTier::default();
         let new_fee_tier_key = FeeTier::new(Percentage::new(U128::from(0)), 2).unwrap();
 
@@ -91,4 +126,33 @@ mod tests {
         assert_eq!(result, vec![fee_tier_key, new_fee_tier_key]);
         assert_eq!(result.len(), 2);
     }
+
+    #[test]
+    fn test_get() {
+        let fee_tier_keys = &mut FeeTiersDeployer::default();
+        let fee_tiers: Vec<FeeTier> = (1..=4)
+            .map(|tick_spacing| FeeTier::new(Percentage::new(U128::from(0)), tick_spacing).unwrap())
+            .collect();
+
+        for fee_tier in fee_tiers.iter() {
+            fee_tier_keys.add(*fee_tier).unwrap();
+        }
+
+        assert_eq!(fee_tier_keys.count(), 4);
+        assert_eq!(fee_tier_keys.get(1, 2), vec![fee_tiers[1], fee_tiers[2]]);
+        assert_eq!(fee_tier_keys.get(3, 10), vec![fee_tiers[3]]);
+        assert_eq!(fee_tier_keys.get(5, 10), vec![]);
+
+        // remaining fee tiers keep the insertion order
+        fee_tier_keys.remove(fee_tiers[1]).unwrap();
+        assert_eq!(fee_tier_keys.count(), 3);
+        assert_eq!(
+            fee_tier_keys.get_all(),
+            vec![fee_tiers[0], fee_tiers[2], fee_tiers[3]]
+        );
+        assert_eq!(fee_tier_keys.get(1, 1), vec![fee_tiers[2]]);
+
+        fee_tier_keys.remove(fee_tiers[3]).unwrap();
+        assert_eq!(fee_tier_keys.get_all(), vec![fee_tiers[0], fee_tiers[2]]);
+    }
 }
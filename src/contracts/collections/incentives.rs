@@ -0,0 +1,189 @@
+use crate::contracts::errors::InvariantError;
+use crate::contracts::{Incentive, Stake};
+use crate::math::token_amount::TokenAmount;
+use odra::types::Address;
+use odra::{Mapping, Variable};
+
+#[odra::module]
+pub struct Incentives {
+    next_incentive_id: Variable<u64>,
+    incentives: Mapping<u64, Option<Incentive>>,
+    stakes: Mapping<u64, Option<Stake>>,
+    rewards: Mapping<(Address, Address), TokenAmount>,
+}
+
+#[odra::module]
+impl Incentives {
+    pub fn add(&mut self, incentive: &Incentive) -> u64 {
+        let id = self.next_incentive_id.get_or_default();
+
+        self.incentives
+            .set(&id, Some(Incentive { id, ..*incentive }));
+
+        self.next_incentive_id.set(id + 1);
+        id
+    }
+
+    pub fn update(&mut self, incentive: &Incentive) -> Result<(), InvariantError> {
+        self.get(incentive.id)?;
+
+        self.incentives.set(&incentive.id, Some(*incentive));
+        Ok(())
+    }
+
+    pub fn get(&self, id: u64) -> Result<Incentive, InvariantError> {
+        let incentive = self
+            .incentives
+            .get(&id)
+            .ok_or(InvariantError::IncentiveNotFound)?
+            .ok_or(InvariantError::IncentiveNotFound)?;
+
+        Ok(incentive)
+    }
+
+    pub fn add_stake(&mut self, position_id: u64, stake: &Stake) -> Result<(), InvariantError> {
+        self.get_stake(position_id)
+            .map_or(Ok(()), |_| Err(InvariantError::PositionAlreadyStaked))?;
+
+        self.stakes.set(&position_id, Some(*stake));
+        Ok(())
+    }
+
+    pub fn remove_stake(&mut self, position_id: u64) -> Result<Stake, InvariantError> {
+        let stake = self.get_stake(position_id)?;
+
+        self.stakes.set(&position_id, None);
+        Ok(stake)
+    }
+
+    pub fn get_stake(&self, position_id: u64) -> Result<Stake, InvariantError> {
+        let stake = self
+            .stakes
+            .get(&position_id)
+            .ok_or(InvariantError::StakeNotFound)?
+            .ok_or(InvariantError::StakeNotFound)?;
+
+        Ok(stake)
+    }
+
+    pub fn add_reward(&mut self, owner: Address, reward_token: Address, amount: TokenAmount) {
+        let reward = self.get_reward(owner, reward_token);
+
+        self.rewards.set(&(owner, reward_token), reward + amount);
+    }
+
+    pub fn take_reward(&mut self, owner: Address, reward_token: Address) -> TokenAmount {
+        let reward = self.get_reward(owner, reward_token);
+
+        self.rewards
+            .set(&(owner, reward_token), TokenAmount::default());
+        reward
+    }
+
+    pub fn get_reward(&self, owner: Address, reward_token: Address) -> TokenAmount {
+        self.rewards.get(&(owner, reward_token)).unwrap_or_default()
+    }
+}
+
+#[cfg(all(test, not(feature = "casper")))]
+mod tests {
+    use super::*;
+    use crate::contracts::PoolKey;
+    use crate::math::liquidity::Liquidity;
+    use crate::math::seconds_per_liquidity::SecondsPerLiquidity;
+    use decimal::*;
+    use odra::types::casper_types::account::AccountHash;
+    use odra::types::{U128, U256};
+
+    fn incentive() -> Incentive {
+        let address = Address::Account(AccountHash::new([0x01; 32]));
+
+        Incentive {
+            id: 0,
+            reward_token: address,
+            pool_key: PoolKey::default(),
+            start_timestamp: 100,
+            end_timestamp: 200,
+            refundee: address,
+            total_reward_unclaimed: TokenAmount::from_integer(1000),
+            total_seconds_claimed: U256::from(0),
+            number_of_stakes: 0,
+        }
+    }
+
+    #[test]
+    fn test_add_and_update() {
+        let incentives = &mut IncentivesDeployer::default();
+
+        let id = incentives.add(&incentive());
+        let next_id = incentives.add(&Incentive {
+            id: 5,
+            ..incentive()
+        });
+
+        assert_eq!(id, 0);
+        assert_eq!(next_id, 1);
+        assert_eq!(incentives.get(next_id).unwrap().id, next_id);
+        assert_eq!(incentives.get(2), Err(InvariantError::IncentiveNotFound));
+
+        let updated = Incentive {
+            number_of_stakes: 1,
+            ..incentives.get(id).unwrap()
+        };
+        incentives.update(&updated).unwrap();
+        assert_eq!(incentives.get(id).unwrap(), updated);
+
+        assert_eq!(
+            incentives.update(&Incentive {
+                id: 2,
+                ..incentive()
+            }),
+            Err(InvariantError::IncentiveNotFound)
+        );
+    }
+
+    #[test]
+    fn test_stakes() {
+        let incentives = &mut IncentivesDeployer::default();
+        let stake = Stake {
+            incentive_id: 0,
+            owner: Address::Account(AccountHash::new([0x02; 32])),
+            liquidity: Liquidity::from_integer(1),
+            seconds_per_liquidity_inside_initial: SecondsPerLiquidity::new(U128::from(0)),
+        };
+
+        incentives.add_stake(0, &stake).unwrap();
+        assert_eq!(incentives.get_stake(0).unwrap(), stake);
+        assert_eq!(
+            incentives.add_stake(0, &stake),
+            Err(InvariantError::PositionAlreadyStaked)
+        );
+
+        assert_eq!(incentives.remove_stake(0).unwrap(), stake);
+        assert_eq!(incentives.get_stake(0), Err(InvariantError::StakeNotFound));
+        assert_eq!(
+            incentives.remove_stake(0),
+            Err(InvariantError::StakeNotFound)
+        );
+    }
+
+    #[test]
+    fn test_rewards() {
+        let incentives = &mut IncentivesDeployer::default();
+        let owner = Address::Account(AccountHash::new([0x02; 32]));
+        let token = Address::Account(AccountHash::new([0x03; 32]));
+
+        incentives.add_reward(owner, token, TokenAmount::from_integer(10));
+        incentives.add_reward(owner, token, TokenAmount::from_integer(5));
+        assert_eq!(
+            incentives.get_reward(owner, token),
+            TokenAmount::from_integer(15)
+        );
+
+        assert_eq!(
+            incentives.take_reward(owner, token),
+            TokenAmount::from_integer(15)
+        );
+        assert_eq!(incentives.get_reward(owner, token), TokenAmount::default());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::contracts::errors::InvariantError;
+use crate::contracts::{LimitOrder, LimitOrderEpoch, PoolKey};
+use odra::{Mapping, Variable};
+
+#[odra::module]
+pub struct LimitOrders {
+    next_epoch_id: Variable<u64>,
+    epochs: Mapping<u64, Option<LimitOrderEpoch>>,
+    active_epochs: Mapping<(PoolKey, i32, bool), Option<u64>>,
+    next_order_id: Variable<u64>,
+    orders: Mapping<u64, Option<LimitOrder>>,
+}
+
+#[odra::module]
+impl LimitOrders {
+    pub fn add_epoch(&mut self, epoch: &LimitOrderEpoch) -> u64 {
+        let id = self.next_epoch_id.get_or_default();
+
+        self.epochs.set(&id, Some(LimitOrderEpoch { id, ..*epoch }));
+        self.active_epochs.set(
+            &(epoch.pool_key, epoch.lower_tick_index, epoch.x_to_y),
+            Some(id),
+        );
+
+        self.next_epoch_id.set(id + 1);
+        id
+    }
+
+    pub fn update_epoch(&mut self, epoch: &LimitOrderEpoch) -> Result<(), InvariantError> {
+        self.get_epoch(epoch.id)?;
+
+        self.epochs.set(&epoch.id, Some(*epoch));
+        Ok(())
+    }
+
+    pub fn get_epoch(&self, id: u64) -> Result<LimitOrderEpoch, InvariantError> {
+        let epoch = self
+            .epochs
+            .get(&id)
+            .ok_or(InvariantError::LimitOrderNotFound)?
+            .ok_or(InvariantError::LimitOrderNotFound)?;
+
+        Ok(epoch)
+    }
+
+    pub fn get_active_epoch(
+        &self,
+        pool_key: PoolKey,
+        lower_tick_index: i32,
+        x_to_y: bool,
+    ) -> Option<LimitOrderEpoch> {
+        self.active_epochs
+            .get(&(pool_key, lower_tick_index, x_to_y))
+            .flatten()
+            .and_then(|id| self.get_epoch(id).ok())
+    }
+
+    pub fn deactivate_epoch(&mut self, pool_key: PoolKey, lower_tick_index: i32, x_to_y: bool) {
+        self.active_epochs
+            .set(&(pool_key, lower_tick_index, x_to_y), None);
+    }
+
+    pub fn add_order(&mut self, order: &LimitOrder) -> u64 {
+        let id = self.next_order_id.get_or_default();
+
+        self.orders.set(&id, Some(LimitOrder { id, ..*order }));
+
+        self.next_order_id.set(id + 1);
+        id
+    }
+
+    pub fn remove_order(&mut self, id: u64) -> Result<LimitOrder, InvariantError> {
+        let order = self.get_order(id)?;
+
+        self.orders.set(&id, None);
+        Ok(order)
+    }
+
+    pub fn get_order(&self, id: u64) -> Result<LimitOrder, InvariantError> {
+        let order = self
+            .orders
+            .get(&id)
+            .ok_or(InvariantError::LimitOrderNotFound)?
+            .ok_or(InvariantError::LimitOrderNotFound)?;
+
+        Ok(order)
+    }
+}
+
+#[cfg(all(test, not(feature = "casper")))]
+mod tests {
+    use super::*;
+    use crate::math::liquidity::Liquidity;
+    use crate::math::token_amount::TokenAmount;
+    use decimal::*;
+    use odra::types::casper_types::account::AccountHash;
+    use odra::types::{Address, U256};
+
+    fn epoch() -> LimitOrderEpoch {
+        LimitOrderEpoch {
+            id: 0,
+            pool_key: PoolKey::default(),
+            lower_tick_index: 10,
+            x_to_y: true,
+            position_id: 0,
+            liquidity: Liquidity::from_integer(1),
+            filled: false,
+            amount_x: TokenAmount::new(U256::from(0)),
+            amount_y: TokenAmount::new(U256::from(0)),
+        }
+    }
+
+    #[test]
+    fn test_epochs() {
+        let limit_orders = &mut LimitOrdersDeployer::default();
+        let pool_key = PoolKey::default();
+
+        assert_eq!(limit_orders.get_active_epoch(pool_key, 10, true), None);
+
+        let id = limit_orders.add_epoch(&epoch());
+        let active = limit_orders.get_active_epoch(pool_key, 10, true).unwrap();
+        assert_eq!(active.id, id);
+        assert_eq!(limit_orders.get_active_epoch(pool_key, 10, false), None);
+
+        let filled = LimitOrderEpoch {
+            filled: true,
+            ..active
+        };
+        limit_orders.update_epoch(&filled).unwrap();
+        limit_orders.deactivate_epoch(pool_key, 10, true);
+
+        assert_eq!(limit_orders.get_active_epoch(pool_key, 10, true), None);
+        assert_eq!(limit_orders.get_epoch(id).unwrap(), filled);
+
+        let next_id = limit_orders.add_epoch(&epoch());
+        assert_eq!(next_id, 1);
+        assert_eq!(
+            limit_orders
+                .get_active_epoch(pool_key, 10, true)
+                .unwrap()
+                .id,
+            next_id
+        );
+        assert_eq!(
+            limit_orders.update_epoch(&LimitOrderEpoch { id: 2, ..epoch() }),
+            Err(InvariantError::LimitOrderNotFound)
+        );
+    }
+
+    #[test]
+    fn test_orders() {
+        let limit_orders = &mut LimitOrdersDeployer::default();
+        let order = LimitOrder {
+            id: 0,
+            owner: Address::Account(AccountHash::new([0x01; 32])),
+            epoch_id: 0,
+            liquidity: Liquidity::from_integer(1),
+        };
+
+        let id = limit_orders.add_order(&order);
+        assert_eq!(limit_orders.get_order(id).unwrap(), order);
+
+        assert_eq!(limit_orders.remove_order(id).unwrap(), order);
+        assert_eq!(
+            limit_orders.get_order(id),
+            Err(InvariantError::LimitOrderNotFound)
+        );
+        assert_eq!(
+            limit_orders.remove_order(id),
+            Err(InvariantError::LimitOrderNotFound)
+        );
+    }
+}
@@ -1,10 +1,16 @@
 pub mod fee_tiers;
+pub mod incentives;
+pub mod limit_orders;
+pub mod oracles;
 pub mod pool_keys;
 pub mod pools;
 pub mod positions;
 pub mod ticks;
 
 pub use fee_tiers::*;
+pub use incentives::*;
+pub use limit_orders::*;
+pub use oracles::*;
 pub use pool_keys::*;
 pub use pools::*;
 pub use positions::*;
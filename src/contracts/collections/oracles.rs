@@ -0,0 +1,322 @@
+use crate::contracts::errors::InvariantError;
+use crate::contracts::{Observation, Oracle, PoolKey};
+use crate::math::MAX_ORACLE_CARDINALITY;
+use odra::prelude::vec::Vec;
+use odra::Mapping;
+
+#[odra::module]
+pub struct Oracles {
+    oracles: Mapping<PoolKey, Option<Oracle>>,
+    observations: Mapping<(PoolKey, u32), Observation>,
+}
+
+#[odra::module]
+impl Oracles {
+    pub fn initialize(&mut self, pool_key: PoolKey, timestamp: u64) -> Result<(), InvariantError> {
+        self.get(pool_key)
+            .map_or(Ok(()), |_| Err(InvariantError::PoolAlreadyExist))?;
+
+        self.oracles.set(&pool_key, Some(Oracle::create()));
+        self.observations
+            .set(&(pool_key, 0), Observation::create(timestamp));
+        Ok(())
+    }
+
+    pub fn write(
+        &mut self,
+        pool_key: PoolKey,
+        timestamp: u64,
+        tick: i32,
+    ) -> Result<(), InvariantError> {
+        let mut oracle = self.get(pool_key)?;
+        let last = self.get_observation(pool_key, oracle.index);
+
+        // one observation per block
+        if last.timestamp == timestamp {
+            return Ok(());
+        }
+
+        let (index, cardinality) = oracle.next_index();
+        oracle.index = index;
+        oracle.cardinality = cardinality;
+
+        self.observations
+            .set(&(pool_key, index), last.transform(timestamp, tick));
+        self.oracles.set(&pool_key, Some(oracle));
+        Ok(())
+    }
+
+    pub fn grow(&mut self, pool_key: PoolKey, cardinality_next: u32) -> Result<(), InvariantError> {
+        let mut oracle = self.get(pool_key)?;
+
+        if cardinality_next > MAX_ORACLE_CARDINALITY {
+            return Err(InvariantError::InvalidOracleCardinality);
+        }
+
+        if cardinality_next <= oracle.cardinality_next {
+            return Ok(());
+        }
+
+        // writing the slots up front moves the storage cost from swaps to the caller
+        for index in oracle.cardinality_next..cardinality_next {
+            self.observations
+                .set(&(pool_key, index), Observation::default());
+        }
+
+        oracle.cardinality_next = cardinality_next;
+        self.oracles.set(&pool_key, Some(oracle));
+        Ok(())
+    }
+
+    pub fn observe(
+        &self,
+        pool_key: PoolKey,
+        timestamp: u64,
+        milliseconds_agos: Vec<u64>,
+        tick: i32,
+    ) -> Result<Vec<i64>, InvariantError> {
+        let oracle = self.get(pool_key)?;
+
+        milliseconds_agos
+            .into_iter()
+            .map(|milliseconds_ago| {
+                self.observe_single(pool_key, oracle, timestamp, milliseconds_ago, tick)
+            })
+            .collect()
+    }
+
+    pub fn get(&self, pool_key: PoolKey) -> Result<Oracle, InvariantError> {
+        let oracle = self
+            .oracles
+            .get(&pool_key)
+            .ok_or(InvariantError::PoolNotFound)?
+            .ok_or(InvariantError::PoolNotFound)?;
+
+        Ok(oracle)
+    }
+
+    pub fn get_observation(&self, pool_key: PoolKey, index: u32) -> Observation {
+        self.observations
+            .get(&(pool_key, index))
+            .unwrap_or_default()
+    }
+
+    fn observe_single(
+        &self,
+        pool_key: PoolKey,
+        oracle: Oracle,
+        timestamp: u64,
+        milliseconds_ago: u64,
+        tick: i32,
+    ) -> Result<i64, InvariantError> {
+        let target = timestamp
+            .checked_sub(milliseconds_ago)
+            .ok_or(InvariantError::ObservationTooOld)?;
+
+        let last = self.get_observation(pool_key, oracle.index);
+
+        if last.timestamp <= target {
+            // extrapolate with the current tick, it has not changed since the last observation
+            return Ok(last.transform(target, tick).tick_cumulative);
+        }
+
+        let (before, after) = self.binary_search(pool_key, oracle, target)?;
+
+        if before.timestamp == target {
+            return Ok(before.tick_cumulative);
+        }
+
+        if after.timestamp == target {
+            return Ok(after.tick_cumulative);
+        }
+
+        Ok(before.interpolate(&after, target))
+    }
+
+    fn binary_search(
+        &self,
+        pool_key: PoolKey,
+        oracle: Oracle,
+        target: u64,
+    ) -> Result<(Observation, Observation), InvariantError> {
+        let cardinality = oracle.cardinality as u64;
+
+        let oldest_index = (oracle.index as u64 + 1) % cardinality;
+        let mut oldest = self.get_observation(pool_key, oldest_index as u32);
+        if !oldest.initialized {
+            oldest = self.get_observation(pool_key, 0);
+        }
+
+        if oldest.timestamp > target {
+            return Err(InvariantError::ObservationTooOld);
+        }
+
+        // half-open range of positions counted from the oldest observation
+        let mut left = oldest_index;
+        let mut right = oldest_index + cardinality;
+
+        while left < right {
+            let middle = (left + right) / 2;
+
+            let before = self.get_observation(pool_key, (middle % cardinality) as u32);
+            if !before.initialized {
+                left = middle + 1;
+                continue;
+            }
+
+            let after = self.get_observation(pool_key, ((middle + 1) % cardinality) as u32);
+
+            let target_at_or_after = before.timestamp <= target;
+            if target_at_or_after && target <= after.timestamp {
+                return Ok((before, after));
+            }
+
+            if !target_at_or_after {
+                right = middle;
+            } else {
+                left = middle + 1;
+            }
+        }
+
+        Err(InvariantError::ObservationTooOld)
+    }
+}
+
+#[cfg(all(test, not(feature = "casper")))]
+mod tests {
+    use super::*;
+    use crate::{contracts::FeeTier, math::percentage::Percentage};
+    use decimal::*;
+    use odra::prelude::vec;
+    use odra::types::casper_types::ContractPackageHash;
+    use odra::types::Address;
+    use odra::types::U128;
+
+    fn pool_key() -> PoolKey {
+        let token_x = Address::Contract(ContractPackageHash::from([0x01; 32]));
+        let token_y = Address::Contract(ContractPackageHash::from([0x02; 32]));
+        let fee_tier = FeeTier::new(Percentage::new(U128::from(0)), 1).unwrap();
+        PoolKey::new(token_x, token_y, fee_tier).unwrap()
+    }
+
+    #[test]
+    fn test_initialize() {
+        let oracles = &mut OraclesDeployer::default();
+        let pool_key = pool_key();
+
+        oracles.initialize(pool_key, 10).unwrap();
+
+        assert_eq!(oracles.get(pool_key).unwrap(), Oracle::create());
+        assert_eq!(
+            oracles.get_observation(pool_key, 0),
+            Observation::create(10)
+        );
+        assert_eq!(
+            oracles.initialize(pool_key, 10),
+            Err(InvariantError::PoolAlreadyExist)
+        );
+    }
+
+    #[test]
+    fn test_write_and_grow() {
+        let oracles = &mut OraclesDeployer::default();
+        let pool_key = pool_key();
+
+        oracles.initialize(pool_key, 0).unwrap();
+
+        // without growing the buffer the only observation is overwritten
+        oracles.write(pool_key, 10, 2).unwrap();
+        assert_eq!(oracles.get(pool_key).unwrap(), Oracle::create());
+        assert_eq!(oracles.get_observation(pool_key, 0).tick_cumulative, 20);
+
+        oracles.grow(pool_key, 3).unwrap();
+        assert_eq!(oracles.get_observation(pool_key, 2), Observation::default());
+        oracles.write(pool_key, 20, 1).unwrap();
+        oracles.write(pool_key, 20, 5).unwrap();
+        oracles.write(pool_key, 30, -1).unwrap();
+
+        assert_eq!(
+            oracles.get(pool_key).unwrap(),
+            Oracle {
+                index: 2,
+                cardinality: 3,
+                cardinality_next: 3,
+            }
+        );
+        assert_eq!(oracles.get_observation(pool_key, 1).tick_cumulative, 30);
+        assert_eq!(oracles.get_observation(pool_key, 2).tick_cumulative, 20);
+
+        // ring buffer wraps around
+        oracles.write(pool_key, 40, 0).unwrap();
+        assert_eq!(oracles.get(pool_key).unwrap().index, 0);
+        assert_eq!(
+            oracles.get_observation(pool_key, 0),
+            Observation {
+                timestamp: 40,
+                tick_cumulative: 20,
+                initialized: true,
+            }
+        );
+
+        assert_eq!(
+            oracles.grow(PoolKey::default(), 3),
+            Err(InvariantError::PoolNotFound)
+        );
+    }
+
+    #[test]
+    fn test_grow_over_max_cardinality() {
+        let oracles = &mut OraclesDeployer::default();
+        let pool_key = pool_key();
+
+        oracles.initialize(pool_key, 0).unwrap();
+
+        assert_eq!(
+            oracles.grow(pool_key, MAX_ORACLE_CARDINALITY + 1),
+            Err(InvariantError::InvalidOracleCardinality)
+        );
+        assert_eq!(oracles.get(pool_key).unwrap(), Oracle::create());
+    }
+
+    #[test]
+    fn test_observe() {
+        let oracles = &mut OraclesDeployer::default();
+        let pool_key = pool_key();
+
+        oracles.initialize(pool_key, 0).unwrap();
+        oracles.grow(pool_key, 4).unwrap();
+        oracles.write(pool_key, 10, 2).unwrap();
+        oracles.write(pool_key, 20, 4).unwrap();
+
+        // current tick is 6 since timestamp 20
+        let result = oracles
+            .observe(pool_key, 30, vec![30, 25, 20, 15, 10, 0], 6)
+            .unwrap();
+        assert_eq!(result, vec![0, 10, 20, 40, 60, 120]);
+
+        assert_eq!(
+            oracles.observe(pool_key, 30, vec![31], 6),
+            Err(InvariantError::ObservationTooOld)
+        );
+    }
+
+    #[test]
+    fn test_observe_after_wrap_around() {
+        let oracles = &mut OraclesDeployer::default();
+        let pool_key = pool_key();
+
+        oracles.initialize(pool_key, 0).unwrap();
+        oracles.grow(pool_key, 2).unwrap();
+        oracles.write(pool_key, 10, 2).unwrap();
+        oracles.write(pool_key, 20, 4).unwrap();
+
+        // the oldest observation is now at index 1
+        let result = oracles.observe(pool_key, 30, vec![20, 15, 10], 6).unwrap();
+        assert_eq!(result, vec![20, 40, 60]);
+
+        assert_eq!(
+            oracles.observe(pool_key, 30, vec![21], 6),
+            Err(InvariantError::ObservationTooOld)
+        );
+    }
+}
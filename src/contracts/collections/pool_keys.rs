@@ -1,46 +1,79 @@
 use crate::contracts::errors::InvariantError;
 use crate::contracts::PoolKey;
 use odra::prelude::vec::Vec;
-use odra::OdraType;
+use odra::{Mapping, Variable};
 
-#[derive(OdraType, Default, Debug)]
+#[odra::module]
 pub struct PoolKeys {
-    pub pool_keys: Vec<PoolKey>,
+    pool_keys: Mapping<u32, Option<PoolKey>>,
+    pool_key_indexes: Mapping<PoolKey, Option<u32>>,
+    pool_keys_length: Variable<u32>,
 }
 
+#[odra::module]
 impl PoolKeys {
     pub fn add(&mut self, pool_key: PoolKey) -> Result<(), InvariantError> {
         if self.contains(pool_key) {
             return Err(InvariantError::PoolKeyAlreadyExist);
         }
 
-        self.pool_keys.push(pool_key);
+        let length = self.count();
+
+        self.pool_keys.set(&length, Some(pool_key));
+        self.pool_key_indexes.set(&pool_key, Some(length));
+        self.pool_keys_length.set(length + 1);
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub fn remove(&mut self, pool_key: PoolKey) -> Result<(), InvariantError> {
         let index = self
-            .pool_keys
-            .iter()
-            .position(|vec_pool_key| *vec_pool_key == pool_key)
+            .pool_key_indexes
+            .get(&pool_key)
+            .flatten()
             .ok_or(InvariantError::PoolKeyNotFound)?;
+        let last_index = self.count() - 1;
+
+        // following elements are shifted down, so the list keeps the insertion order
+        for current_index in index..last_index {
+            let next_pool_key = self
+                .pool_keys
+                .get(&(current_index + 1))
+                .flatten()
+                .ok_or(InvariantError::PoolKeyNotFound)?;
+
+            self.pool_keys.set(&current_index, Some(next_pool_key));
+            self.pool_key_indexes
+                .set(&next_pool_key, Some(current_index));
+        }
 
-        self.pool_keys.remove(index);
-
+        self.pool_keys.set(&last_index, None);
+        self.pool_key_indexes.set(&pool_key, None);
+        self.pool_keys_length.set(last_index);
         Ok(())
     }
 
-    pub fn contains(&self, fee_tier_key: PoolKey) -> bool {
-        self.pool_keys.contains(&fee_tier_key)
+    pub fn contains(&self, pool_key: PoolKey) -> bool {
+        self.pool_key_indexes.get(&pool_key).flatten().is_some()
+    }
+
+    pub fn get(&self, offset: u32, limit: u32) -> Vec<PoolKey> {
+        let end = self.count().min(offset.saturating_add(limit));
+
+        (offset..end)
+            .flat_map(|index| self.pool_keys.get(&index).flatten())
+            .collect()
     }
 
     pub fn get_all(&self) -> Vec<PoolKey> {
-        self.pool_keys.clone()
+        self.get(0, self.count())
+    }
+
+    pub fn count(&self) -> u32 {
+        self.pool_keys_length.get_or_default()
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "casper")))]
 mod tests {
     use super::*;
     use crate::{contracts::FeeTier, math::percentage::Percentage};
@@ -51,7 +84,7 @@ mod tests {
 
     #[test]
     fn test_add() {
-        let pool_keys = &mut PoolKeys::default();
+        let pool_keys = &mut PoolKeysDeployer::default();
         let pool_key = PoolKey::default();
         let token_x: Address = Address::Contract(ContractPackageHash::from([0x01; 32]));
         let token_y: Address = Address::Contract(ContractPackageHash::from([0x02; 32]));
@@ -71,7 +104,7 @@ mod tests {
 
     #[test]
     fn test_remove() {
-        let pool_keys = &mut PoolKeys::default();
+        let pool_keys = &mut PoolKeysDeployer::default();
         let pool_key = PoolKey::default();
 
         pool_keys.add(pool_key).unwrap();
@@ -85,7 +118,7 @@ mod tests {
 
     #[test]
     fn test_get_all() {
-        let pool_keys = &mut PoolKeys::default();
+        let pool_keys = &mut PoolKeysDeployer::default();
         let pool_key = PoolKey::default();
         let token_x: Address = Address::Contract(ContractPackageHash::from([0x01; 32]));
         let token_y: Address = Address::Contract(ContractPackageHash::from([0x02; 32]));
@@ -106,4 +139,37 @@ mod tests {
         assert_eq!(result, vec![pool_key, new_pool_key]);
         assert_eq!(result.len(), 2);
     }
+
+    #[test]
+    fn test_get() {
+        let pool_keys = &mut PoolKeysDeployer::default();
+        let token_x: Address = Address::Contract(ContractPackageHash::from([0x01; 32]));
+        let token_y: Address = Address::Contract(ContractPackageHash::from([0x02; 32]));
+        let keys: Vec<PoolKey> = (1..=3)
+            .map(|tick_spacing| {
+                let fee_tier = FeeTier {
+                    fee: Percentage::new(U128::from(0)),
+                    tick_spacing,
+                };
+                PoolKey::new(token_x, token_y, fee_tier).unwrap()
+            })
+            .collect();
+
+        for pool_key in keys.iter() {
+            pool_keys.add(*pool_key).unwrap();
+        }
+
+        assert_eq!(pool_keys.count(), 3);
+        assert_eq!(pool_keys.get(0, 2), vec![keys[0], keys[1]]);
+        assert_eq!(pool_keys.get(2, 2), vec![keys[2]]);
+        assert_eq!(pool_keys.get(3, 1), vec![]);
+
+        // remaining pool keys keep the insertion order
+        pool_keys.remove(keys[0]).unwrap();
+        assert_eq!(pool_keys.get_all(), vec![keys[1], keys[2]]);
+        assert!(!pool_keys.contains(keys[0]));
+
+        pool_keys.add(keys[0]).unwrap();
+        assert_eq!(pool_keys.get_all(), vec![keys[1], keys[2], keys[0]]);
+    }
 }
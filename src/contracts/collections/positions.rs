@@ -2,95 +2,167 @@ use crate::contracts::errors::InvariantError;
 use crate::contracts::Position;
 use odra::prelude::vec::Vec;
 use odra::types::Address;
-use odra::Mapping;
+use odra::{Mapping, Variable};
 
 #[odra::module]
 pub struct Positions {
+    next_position_id: Variable<u64>,
+    positions: Mapping<u64, Option<Position>>,
+    position_owners: Mapping<u64, Option<Address>>,
     positions_length: Mapping<Address, u32>,
-    positions: Mapping<(Address, u32), Option<Position>>,
+    position_ids: Mapping<(Address, u32), u64>,
+    position_indexes: Mapping<u64, u32>,
+    position_approvals: Mapping<u64, Option<Address>>,
+    operator_approvals: Mapping<(Address, Address), bool>,
 }
 
 #[odra::module]
 impl Positions {
-    pub fn add(&mut self, account_id: Address, position: &Position) {
-        let positions_length = self.get_length(account_id);
+    pub fn add(&mut self, account_id: Address, position: &Position) -> u64 {
+        let id = self.next_position_id.get_or_default();
 
-        self.positions
-            .set(&(account_id, positions_length), Some(*position));
+        self.positions.set(&id, Some(Position { id, ..*position }));
+        self.link(account_id, id);
 
-        self.positions_length.add(&account_id, 1);
+        self.next_position_id.set(id + 1);
+        id
     }
 
-    pub fn update(
-        &mut self,
-        account_id: Address,
-        index: u32,
-        position: &Position,
-    ) -> Result<(), InvariantError> {
-        let positions_length = self.get_length(account_id);
-
-        if index >= positions_length {
-            return Err(InvariantError::PositionNotFound);
-        }
+    pub fn update(&mut self, id: u64, position: &Position) -> Result<(), InvariantError> {
+        self.get(id)?;
 
-        self.positions.set(&(account_id, index), Some(*position));
+        self.positions.set(&id, Some(*position));
         Ok(())
     }
 
-    pub fn remove(&mut self, account_id: Address, index: u32) -> Result<Position, InvariantError> {
-        let positions_length = self.get_length(account_id);
-        let position = self.get(account_id, index)?;
+    pub fn remove(&mut self, id: u64) -> Result<Position, InvariantError> {
+        let position = self.get(id)?;
+        let account_id = self.get_owner(id)?;
 
-        if index < positions_length - 1 {
-            let last_position = self
-                .positions
-                .get(&(account_id, positions_length - 1))
-                .unwrap();
-            self.positions
-                .set(&(account_id, positions_length - 1), None);
-            self.positions.set(&(account_id, index), last_position);
-        } else {
-            self.positions.set(&(account_id, index), None);
-        }
+        self.unlink(account_id, id);
+        self.positions.set(&id, None);
 
-        self.positions_length.subtract(&account_id, 1);
         Ok(position)
     }
 
     pub fn transfer(
         &mut self,
-        account_id: Address,
-        index: u32,
+        id: u64,
         receiver_account_id: Address,
     ) -> Result<(), InvariantError> {
-        let position = self.remove(account_id, index)?;
-        self.add(receiver_account_id, &position);
+        let account_id = self.get_owner(id)?;
+
+        self.unlink(account_id, id);
+        self.link(receiver_account_id, id);
         Ok(())
     }
 
-    pub fn get(&self, account_id: Address, index: u32) -> Result<Position, InvariantError> {
+    pub fn approve(&mut self, id: u64, operator: Address) -> Result<(), InvariantError> {
+        self.get_owner(id)?;
+
+        self.position_approvals.set(&id, Some(operator));
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, id: u64) -> Result<(), InvariantError> {
+        self.get_owner(id)?;
+
+        self.position_approvals.set(&id, None);
+        Ok(())
+    }
+
+    pub fn set_operator(&mut self, account_id: Address, operator: Address, approved: bool) {
+        self.operator_approvals
+            .set(&(account_id, operator), approved);
+    }
+
+    pub fn is_approved(&self, id: u64, operator: Address) -> Result<bool, InvariantError> {
+        let account_id = self.get_owner(id)?;
+
+        Ok(account_id == operator
+            || self.position_approvals.get(&id).flatten() == Some(operator)
+            || self
+                .operator_approvals
+                .get(&(account_id, operator))
+                .unwrap_or(false))
+    }
+
+    pub fn get(&self, id: u64) -> Result<Position, InvariantError> {
         let position = self
             .positions
-            .get(&(account_id, index))
+            .get(&id)
             .ok_or(InvariantError::PositionNotFound)?
             .ok_or(InvariantError::PositionNotFound)?;
 
         Ok(position)
     }
 
-    pub fn get_all(&self, account_id: Address) -> Vec<Position> {
+    pub fn get_owner(&self, id: u64) -> Result<Address, InvariantError> {
+        let owner = self
+            .position_owners
+            .get(&id)
+            .ok_or(InvariantError::PositionNotFound)?
+            .ok_or(InvariantError::PositionNotFound)?;
+
+        Ok(owner)
+    }
+
+    pub fn get_ids(&self, account_id: Address) -> Vec<u64> {
         (0..self.get_length(account_id))
-            .flat_map(|index| {
-                self.positions.get(&(account_id, index))
-                // .map(|opt_position| opt_position)
-            })
-            .flatten()
+            .flat_map(|index| self.position_ids.get(&(account_id, index)))
+            .collect()
+    }
+
+    pub fn get_all(&self, account_id: Address) -> Vec<Position> {
+        self.get_ids(account_id)
+            .into_iter()
+            .flat_map(|id| self.get(id))
+            .collect()
+    }
+
+    pub fn get_paginated(&self, account_id: Address, offset: u32, limit: u32) -> Vec<Position> {
+        let end = self
+            .get_length(account_id)
+            .min(offset.saturating_add(limit));
+
+        (offset..end)
+            .flat_map(|index| self.position_ids.get(&(account_id, index)))
+            .flat_map(|id| self.get(id))
             .collect()
     }
 
     pub fn get_length(&self, account_id: Address) -> u32 {
         self.positions_length.get(&account_id).unwrap_or(0)
     }
+
+    fn link(&mut self, account_id: Address, id: u64) {
+        let positions_length = self.get_length(account_id);
+
+        self.position_owners.set(&id, Some(account_id));
+        self.position_ids.set(&(account_id, positions_length), id);
+        self.position_indexes.set(&id, positions_length);
+
+        self.positions_length.add(&account_id, 1);
+    }
+
+    fn unlink(&mut self, account_id: Address, id: u64) {
+        let positions_length = self.get_length(account_id);
+        let index = self.position_indexes.get(&id).unwrap_or_default();
+
+        // order of the owner ids list is not preserved, ids themselves never change
+        if index < positions_length - 1 {
+            let last_id = self
+                .position_ids
+                .get(&(account_id, positions_length - 1))
+                .unwrap_or_default();
+            self.position_ids.set(&(account_id, index), last_id);
+            self.position_indexes.set(&last_id, index);
+        }
+
+        self.position_owners.set(&id, None);
+        self.position_approvals.set(&id, None);
+        self.positions_length.subtract(&account_id, 1);
+    }
 }
 
 #[cfg(all(test, not(feature = "casper")))]
@@ -105,20 +177,21 @@ mod tests {
         let account_id = Address::Account(AccountHash::new([0x01; 32]));
         let position = Position::default();
         let new_position = Position {
+            id: 1,
             lower_tick_index: -1,
             upper_tick_index: 1,
             ..Position::default()
         };
 
-        positions.add(account_id, &position);
-        positions.add(account_id, &new_position);
+        let id = positions.add(account_id, &position);
+        let new_id = positions.add(account_id, &new_position);
 
-        assert_eq!(positions.get(account_id, 0).unwrap(), position);
-        assert_eq!(positions.get(account_id, 1).unwrap(), new_position);
-        assert_eq!(
-            positions.get(account_id, 2),
-            Err(InvariantError::PositionNotFound)
-        );
+        assert_eq!(id, 0);
+        assert_eq!(new_id, 1);
+        assert_eq!(positions.get(0).unwrap(), position);
+        assert_eq!(positions.get(1).unwrap(), new_position);
+        assert_eq!(positions.get(2), Err(InvariantError::PositionNotFound));
+        assert_eq!(positions.get_owner(1).unwrap(), account_id);
         assert_eq!(positions.get_length(account_id), 2);
     }
 
@@ -127,21 +200,22 @@ mod tests {
         let positions = &mut PositionsDeployer::default();
         let account_id = Address::Account(AccountHash::new([0x01; 32]));
         let position = Position::default();
+
+        positions.add(account_id, &position);
+
         let new_position = Position {
             lower_tick_index: -1,
             upper_tick_index: 1,
-            ..Position::default()
+            ..position
         };
 
-        positions.add(account_id, &position);
-
-        positions.update(account_id, 0, &new_position).unwrap();
+        positions.update(0, &new_position).unwrap();
 
-        assert_eq!(positions.get(account_id, 0).unwrap(), new_position);
+        assert_eq!(positions.get(0).unwrap(), new_position);
         assert_eq!(positions.get_length(account_id), 1);
 
         assert_eq!(
-            positions.update(account_id, 1, &new_position),
+            positions.update(1, &new_position),
             Err(InvariantError::PositionNotFound)
         );
     }
@@ -152,6 +226,7 @@ mod tests {
         let account_id = Address::Account(AccountHash::new([0x01; 32]));
         let position = Position::default();
         let new_position = Position {
+            id: 1,
             lower_tick_index: -1,
             upper_tick_index: 1,
             ..Position::default()
@@ -160,50 +235,100 @@ mod tests {
         positions.add(account_id, &position);
         positions.add(account_id, &new_position);
 
-        let result = positions.remove(account_id, 0).unwrap();
+        let result = positions.remove(0).unwrap();
         assert_eq!(result, position);
-        assert_eq!(positions.get(account_id, 0).unwrap(), new_position);
+        assert_eq!(positions.get(0), Err(InvariantError::PositionNotFound));
+        assert_eq!(positions.get(1).unwrap(), new_position);
+        assert_eq!(positions.get_ids(account_id), vec![1]);
         assert_eq!(positions.get_length(account_id), 1);
 
-        let result = positions.remove(account_id, 0).unwrap();
+        let result = positions.remove(1).unwrap();
         assert_eq!(result, new_position);
+        assert_eq!(positions.get(1), Err(InvariantError::PositionNotFound));
         assert_eq!(
-            positions.get(account_id, 0),
+            positions.get_owner(1),
             Err(InvariantError::PositionNotFound)
         );
         assert_eq!(positions.get_length(account_id), 0);
 
+        assert_eq!(positions.remove(1), Err(InvariantError::PositionNotFound));
+
+        let next_position = Position::default();
+        let next_id = positions.add(account_id, &next_position);
+        assert_eq!(next_id, 2);
+    }
+
+    #[test]
+    fn test_transfer() {
+        let positions = &mut PositionsDeployer::default();
+        let account_id = Address::Account(AccountHash::new([0x01; 32]));
+        let receiver_account_id = Address::Account(AccountHash::new([0x02; 32]));
+        let position = Position::default();
+        let new_position = Position {
+            id: 1,
+            lower_tick_index: -1,
+            upper_tick_index: 1,
+            ..Position::default()
+        };
+
+        positions.add(account_id, &position);
+        positions.add(receiver_account_id, &new_position);
+
+        positions.transfer(0, receiver_account_id).unwrap();
+
+        assert_eq!(positions.get(0).unwrap(), position);
+        assert_eq!(positions.get_owner(0).unwrap(), receiver_account_id);
+        assert_eq!(positions.get_length(account_id), 0);
+        assert_eq!(positions.get_ids(receiver_account_id), vec![1, 0]);
+        assert_eq!(positions.get_length(receiver_account_id), 2);
+
         assert_eq!(
-            positions.remove(account_id, 0),
+            positions.transfer(2, receiver_account_id),
             Err(InvariantError::PositionNotFound)
         )
     }
 
     #[test]
-    fn test_transfer() {
+    fn test_approvals() {
         let positions = &mut PositionsDeployer::default();
         let account_id = Address::Account(AccountHash::new([0x01; 32]));
-        let receiver_account_id = Address::Account(AccountHash::new([0x02; 32]));
+        let operator = Address::Account(AccountHash::new([0x02; 32]));
+        let receiver_account_id = Address::Account(AccountHash::new([0x03; 32]));
         let position = Position::default();
 
+        positions.add(account_id, &position);
         positions.add(account_id, &position);
 
-        positions
-            .transfer(account_id, 0, receiver_account_id)
-            .unwrap();
+        assert!(positions.is_approved(0, account_id).unwrap());
+        assert!(!positions.is_approved(0, operator).unwrap());
+
+        positions.approve(0, operator).unwrap();
+        assert!(positions.is_approved(0, operator).unwrap());
+        assert!(!positions.is_approved(1, operator).unwrap());
+
+        positions.revoke(0).unwrap();
+        assert!(!positions.is_approved(0, operator).unwrap());
+
+        positions.set_operator(account_id, operator, true);
+        assert!(positions.is_approved(0, operator).unwrap());
+        assert!(positions.is_approved(1, operator).unwrap());
+
+        positions.set_operator(account_id, operator, false);
+        assert!(!positions.is_approved(1, operator).unwrap());
+
+        // approval of a single position is dropped when it changes owner
+        positions.approve(0, operator).unwrap();
+        positions.transfer(0, receiver_account_id).unwrap();
+        assert!(!positions.is_approved(0, operator).unwrap());
 
         assert_eq!(
-            positions.get(account_id, 0),
+            positions.approve(2, operator),
             Err(InvariantError::PositionNotFound)
         );
-        assert_eq!(positions.get_length(account_id), 0);
-        assert_eq!(positions.get(receiver_account_id, 0).unwrap(), position);
-        assert_eq!(positions.get_length(receiver_account_id), 1);
-
         assert_eq!(
-            positions.transfer(account_id, 0, receiver_account_id),
+            positions.is_approved(2, operator),
             Err(InvariantError::PositionNotFound)
-        )
+        );
     }
 
     #[test]
@@ -212,6 +337,7 @@ mod tests {
         let account_id = Address::Account(AccountHash::new([0x01; 32]));
         let position = Position::default();
         let new_position = Position {
+            id: 1,
             lower_tick_index: -1,
             upper_tick_index: 1,
             ..Position::default()
@@ -228,7 +354,15 @@ mod tests {
         let result = positions.get_all(account_id);
         assert_eq!(result, vec![position, new_position]);
         assert_eq!(result.len(), 2);
+        assert_eq!(positions.get_ids(account_id), vec![0, 1]);
         assert_eq!(positions.get_length(account_id), 2);
+
+        assert_eq!(positions.get_paginated(account_id, 0, 1), vec![position]);
+        assert_eq!(
+            positions.get_paginated(account_id, 1, 5),
+            vec![new_position]
+        );
+        assert_eq!(positions.get_paginated(account_id, 2, 5), vec![]);
     }
 
     #[test]
@@ -237,6 +371,7 @@ mod tests {
         let account_id = Address::Account(AccountHash::new([0x01; 32]));
         let position = Position::default();
         let new_position = Position {
+            id: 1,
             lower_tick_index: -1,
             upper_tick_index: 1,
             ..Position::default()
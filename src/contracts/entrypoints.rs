@@ -1,7 +1,13 @@
-use super::{FeeTier, InvariantError, Pool, PoolKey, Position, Tick};
+use super::{
+    Call, CallResult, FeeTier, Incentive, InvariantError, LimitOrder, LimitOrderEpoch, PauseKind,
+    PauseStatus, Pool, PoolKey, Position, Role, Stake, Tick,
+};
 use crate::{
-    math::{percentage::Percentage, token_amount::TokenAmount},
-    CalculateSwapResult, QuoteResult, SwapHop,
+    math::{
+        percentage::Percentage, seconds_per_liquidity::SecondsPerLiquidity,
+        token_amount::TokenAmount,
+    },
+    CalculateSwapResult, ObserveResult, QuoteResult, QuoteRouteResult, SwapHop,
 };
 
 use odra::{
@@ -10,12 +16,15 @@ use odra::{
 };
 
 pub trait Entrypoints {
-    /// Allows admin to add a custom fee tier.
+    /// Allows admin or a `FeeTierManager` to add a custom fee tier.
     ///
     /// # Parameters
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
     ///
+    /// # Events
+    /// - Emits an `Add Fee Tier` event upon success.
+    ///
     /// # Errors
     /// - Fails if an unauthorized user attempts to create a fee tier.
     /// - Fails if the tick spacing is invalid.
@@ -30,12 +39,15 @@ pub trait Entrypoints {
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
     fn fee_tier_exist(&self, fee: U128, tick_spacing: u32) -> bool;
 
-    /// Removes an existing fee tier.
+    /// Allows admin or a `FeeTierManager` to remove an existing fee tier.
     ///
     /// # Parameters
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
     ///
+    /// # Events
+    /// - Emits a `Remove Fee Tier` event upon success.
+    ///
     /// # Errors
     /// - Fails if an unauthorized user attempts to remove a fee tier.
     /// - Fails if fee tier does not exist
@@ -44,6 +56,13 @@ pub trait Entrypoints {
     /// Retrieves available fee tiers
     fn get_fee_tiers(&self) -> Vec<FeeTier>;
 
+    /// Retrieves a page of available fee tiers together with the total number of fee tiers.
+    ///
+    /// # Parameters
+    /// - `offset`: The index of the first fee tier on the page.
+    /// - `limit`: The maximal number of fee tiers on the page.
+    fn get_fee_tiers_paginated(&self, offset: u32, limit: u32) -> (Vec<FeeTier>, u32);
+
     /// Allows a user to create a custom pool on a specified token pair and fee tier.
     /// The contract specifies the order of tokens as x and y, the lower token address assigned as token x.
     /// The choice is deterministic.
@@ -56,6 +75,9 @@ pub trait Entrypoints {
     /// - `init_sqrt_price`: The square root of the price for the initial pool related to `init_tick`.
     /// - `init_tick`: The initial tick at which the pool will be created.
     ///
+    /// # Events
+    /// - Emits a `Create Pool` event upon success.
+    ///
     /// # Errors
     /// - Fails if the specified fee tier cannot be found.
     /// - Fails if the user attempts to create a pool for the same tokens.
@@ -93,7 +115,14 @@ pub trait Entrypoints {
     /// Retrieves listed pools
     fn get_pools(&self) -> Vec<PoolKey>;
 
-    /// Retrieves the protocol fee represented as a percentage.
+    /// Retrieves a page of listed pools together with the total number of pools.
+    ///
+    /// # Parameters
+    /// - `offset`: The index of the first pool on the page.
+    /// - `limit`: The maximal number of pools on the page.
+    fn get_pools_paginated(&self, offset: u32, limit: u32) -> (Vec<PoolKey>, u32);
+
+    /// Retrieves the global protocol fee represented as a percentage.
     fn get_protocol_fee(&self) -> Percentage;
 
     /// Allows an fee receiver to withdraw collected fees.
@@ -104,6 +133,9 @@ pub trait Entrypoints {
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
     ///
+    /// # Events
+    /// - Emits a `Withdraw Protocol Fee` event upon success.
+    ///
     /// # Errors
     /// - Reverts the call when the caller is an unauthorized receiver.
     ///
@@ -117,273 +149,1605 @@ pub trait Entrypoints {
         tick_spacing: u32,
     ) -> Result<(), InvariantError>;
 
-    /// Allows an admin to adjust the protocol fee.
+    /// Allows admin or a `ProtocolFeeManager` to adjust the protocol fee.
     ///
     /// # Parameters
     /// - `protocol_fee`: The expected fee represented as a percentage.
     ///
+    /// # Events
+    /// - Emits a `Change Protocol Fee` event upon success.
+    ///
     /// # Errors
     /// - Reverts the call when the caller is an unauthorized user.
     fn change_protocol_fee(&mut self, protocol_fee: U128) -> Result<(), InvariantError>;
 
-    /// Allows admin to change current fee receiver.
+    /// Allows admin or a `ProtocolFeeManager` to set the protocol fee of a single pool.
+    /// The pool protocol fee takes precedence over the global protocol fee.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
     /// - `token_1`: The address of the second token.
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
-    /// - `fee_receiver`: An `AccountId` identifying the user authorized to claim fees.
+    /// - `protocol_fee`: The expected fee represented as a percentage.
     ///
     /// # Errors
     /// - Reverts the call when the caller is an unauthorized user.
-    fn change_fee_receiver(
+    /// - Fails if pool does not exist
+    fn change_pool_protocol_fee(
         &mut self,
         token_0: Address,
         token_1: Address,
         fee: U128,
         tick_spacing: u32,
-        fee_receiver: Address,
+        protocol_fee: U128,
     ) -> Result<(), InvariantError>;
 
-    /// Checks if the tick at a specified index is initialized.
+    /// Allows admin or a `ProtocolFeeManager` to remove the protocol fee of a single pool.
+    /// The pool falls back to the global protocol fee.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
     /// - `token_1`: The address of the second token.
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
-    /// - `index`: The tick index in the tickmap.
-    fn is_tick_initialized(
-        &self,
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    /// - Fails if pool does not exist
+    fn reset_pool_protocol_fee(
+        &mut self,
         token_0: Address,
         token_1: Address,
         fee: U128,
         tick_spacing: u32,
-        index: i32,
-    ) -> bool;
+    ) -> Result<(), InvariantError>;
 
-    /// Retrieves information about a tick at a specified index.
+    /// Retrieves the protocol fee applied to swaps in a pool, either set for the pool or the global one.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
     /// - `token_1`: The address of the second token.
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
-    /// - `index`: The tick index in the tickmap.
     ///
     /// # Errors
-    /// - Fails if tick cannot be found
-    fn get_tick(
+    /// - Fails if pool does not exist
+    fn get_pool_protocol_fee(
         &self,
         token_0: Address,
         token_1: Address,
         fee: U128,
         tick_spacing: u32,
-        index: i32,
-    ) -> Result<Tick, InvariantError>;
-
-    /// Allows an authorized user (owner of the position) to claim collected fees.
-    ///
-    /// # Parameters
-    /// - `index`: The index of the user position from which fees will be claimed.
-    ///
-    /// # Errors
-    /// - Fails if the position cannot be found.
-    ///
-    /// # External contracts
-    /// - odra::Erc20
-    fn claim_fee(&mut self, index: u32) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+    ) -> Result<Percentage, InvariantError>;
 
-    /// Opens a position.
+    /// Allows admin or a `FeeReceiverManager` to change current fee receiver.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
     /// - `token_1`: The address of the second token.
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
-    /// - `lower_tick`: The index of the lower tick for opening the position.
-    /// - `upper_tick`: The index of the upper tick for opening the position.
-    /// - `liquidity_delta`: The desired liquidity provided by the user in the specified range.
-    /// - `slippage_limit_lower`: The price limit for downward movement to execute the position creation.
-    /// - `slippage_limit_upper`: The price limit for upward movement to execute the position creation.
+    /// - `fee_receiver`: An `AccountId` identifying the user authorized to claim fees.
     ///
     /// # Events
-    /// - On successful transfer, emits a `Create Position` event for the newly opened position.
+    /// - Emits a `Change Fee Receiver` event upon success.
     ///
     /// # Errors
-    /// - Fails if the user attempts to open a position with zero liquidity.
-    /// - Fails if the user attempts to create a position with invalid tick indexes or tick spacing.
-    /// - Fails if the price has reached the slippage limit.
-    /// - Fails if the allowance is insufficient or the user balance transfer fails.
-    /// - Fails if pool does not exist
-    ///
-    /// # External contracts
-    /// - odra::Erc20
-    #[allow(clippy::too_many_arguments)]
-    fn create_position(
+    /// - Reverts the call when the caller is an unauthorized user.
+    fn change_fee_receiver(
         &mut self,
         token_0: Address,
         token_1: Address,
         fee: U128,
         tick_spacing: u32,
-        lower_tick: i32,
-        upper_tick: i32,
-        liquidity_delta: U256,
-        slippage_limit_lower: U128,
-        slippage_limit_upper: U128,
-    ) -> Result<Position, InvariantError>;
+        fee_receiver: Address,
+    ) -> Result<(), InvariantError>;
 
-    /// Transfers a position between users.
+    /// Allows admin to propose a new admin. The change takes effect once the proposed admin accepts it.
+    /// Proposing again replaces the previous proposal.
     ///
     /// # Parameters
-    /// - `index`: The index of the user position to transfer.
-    /// - `receiver`: An `AccountId` identifying the user who will own the position.
-    fn transfer_position(&mut self, index: u32, receiver: Address) -> Result<(), InvariantError>;
+    /// - `new_admin`: An `Address` identifying the proposed admin.
+    ///
+    /// # Events
+    /// - Emits an `Admin Proposed` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is not the admin.
+    fn propose_admin(&mut self, new_admin: Address) -> Result<(), InvariantError>;
+
+    /// Allows the proposed admin to accept the admin rights.
+    ///
+    /// # Events
+    /// - Emits an `Admin Changed` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is not the proposed admin.
+    fn accept_admin(&mut self) -> Result<(), InvariantError>;
 
-    /// Removes a position. Sends tokens associated with specified position to the owner.
+    /// Retrieves the current admin.
+    fn get_admin(&self) -> Address;
+
+    /// Retrieves the proposed admin waiting for acceptance, if any.
+    fn get_pending_admin(&self) -> Option<Address>;
+
+    /// Allows admin to grant a role. The admin is allowed to perform actions of every role.
     ///
     /// # Parameters
-    /// - `index`: The index of the user position to be removed.
+    /// - `role`: The granted `Role`.
+    /// - `account`: An `Address` identifying the user receiving the role.
     ///
     /// # Events
-    /// - Emits a `Remove Position` event upon success.
+    /// - Emits a `Role Granted` event upon success.
     ///
     /// # Errors
-    /// - Fails if Position cannot be found
+    /// - Reverts the call when the caller is not the admin.
+    fn grant_role(&mut self, role: Role, account: Address) -> Result<(), InvariantError>;
+
+    /// Allows admin to revoke a role.
     ///
-    /// # External contracts
-    /// - odra::Erc20
-    fn remove_position(&mut self, index: u32)
-        -> Result<(TokenAmount, TokenAmount), InvariantError>;
+    /// # Parameters
+    /// - `role`: The revoked `Role`.
+    /// - `account`: An `Address` identifying the user losing the role.
+    ///
+    /// # Events
+    /// - Emits a `Role Revoked` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is not the admin.
+    fn revoke_role(&mut self, role: Role, account: Address) -> Result<(), InvariantError>;
 
-    /// Retrieves information about a single position.
+    /// Query of whether the role was granted to the user.
     ///
     /// # Parameters
-    /// - `index`: The index of the user position.
-    /// - 'owner': An `Address` identifying the user who owns the positions.
+    /// - `role`: The checked `Role`.
+    /// - `account`: An `Address` identifying the user.
+    fn has_role(&self, role: Role, account: Address) -> bool;
+
+    /// Allows admin or a `Pauser` to pause swaps, positions or both in every pool.
+    /// Paused positions cannot be created or increased, while `remove_position`, `decrease_liquidity` and `claim_fee` stay available.
+    ///
+    /// # Parameters
+    /// - `kind`: The `PauseKind` selecting paused operations.
+    ///
+    /// # Events
+    /// - Emits a `Change Pause Status` event upon success.
     ///
     /// # Errors
-    /// - Fails if position cannot be found
-    fn get_position(&mut self, owner: Address, index: u32) -> Result<Position, InvariantError>;
+    /// - Reverts the call when the caller is an unauthorized user.
+    fn pause(&mut self, kind: PauseKind) -> Result<(), InvariantError>;
 
-    /// Retrieves a vector containing all positions held by the user.
+    /// Allows admin or a `Pauser` to resume operations paused with `pause`.
     ///
     /// # Parameters
-    /// - 'owner': An `Address` identifying the user who owns the positions.
-    fn get_all_positions(&mut self, owner: Address) -> Vec<Position>;
+    /// - `kind`: The `PauseKind` selecting resumed operations.
+    ///
+    /// # Events
+    /// - Emits a `Change Pause Status` event upon success.
+    ///
+    /// # Errors
+    /// - Reverts the call when the caller is an unauthorized user.
+    fn unpause(&mut self, kind: PauseKind) -> Result<(), InvariantError>;
 
-    /// Simulates the swap without its execution.
+    /// Retrieves operations paused in every pool.
+    fn get_pause_status(&self) -> PauseStatus;
+
+    /// Allows admin or a `Pauser` to pause swaps, positions or both in a single pool.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
     /// - `token_1`: The address of the second token.
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
-    /// - `x_to_y`: A boolean specifying the swap direction.
-    /// - `amount`: The amount of tokens that the user wants to swap.
-    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
-    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `kind`: The `PauseKind` selecting paused operations.
+    ///
+    /// # Events
+    /// - Emits a `Change Pool Pause Status` event upon success.
     ///
     /// # Errors
-    /// - Fails if the user attempts to perform a swap with zero amounts.
-    /// - Fails if the price has reached the specified limit.
-    /// - Fails if the user would receive zero tokens.
+    /// - Reverts the call when the caller is an unauthorized user.
     /// - Fails if pool does not exist
-    #[allow(clippy::too_many_arguments)]
-    fn quote(
-        &self,
+    fn pause_pool(
+        &mut self,
         token_0: Address,
         token_1: Address,
         fee: U128,
         tick_spacing: u32,
-        x_to_y: bool,
-        amount: U256,
-        by_amount_in: bool,
-        sqrt_price_limit: U256,
-    ) -> Result<QuoteResult, InvariantError>;
+        kind: PauseKind,
+    ) -> Result<(), InvariantError>;
 
-    /// Performs a single swap based on the provided parameters.
+    /// Allows admin or a `Pauser` to resume operations paused with `pause_pool`.
+    /// Operations paused globally stay paused.
     ///
     /// # Parameters
     /// - `token_0`: The address of the first token.
     /// - `token_1`: The address of the second token.
     /// - `fee`: A value identifying the pool fee determined in percentages.
     /// - `tick_spacing`: The tick spacing for the specified fee tier.
-    /// - `x_to_y`: A boolean specifying the swap direction.
-    /// - `amount`: TokenAmount that the user wants to swap.
-    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
-    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `kind`: The `PauseKind` selecting resumed operations.
     ///
     /// # Events
-    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
-    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    /// - Emits a `Change Pool Pause Status` event upon success.
     ///
     /// # Errors
-    /// - Fails if the user attempts to perform a swap with zero amounts.
-    /// - Fails if the price has reached the specified price limit (or price associated with specified square root of price).
-    /// - Fails if the user would receive zero tokens.
-    /// - Fails if the allowance is insufficient or the user balance transfer fails.
-    /// - Fails if there is insufficient liquidity in pool
+    /// - Reverts the call when the caller is an unauthorized user.
     /// - Fails if pool does not exist
-    ///
-    /// # External contracts
-    /// - odra::Erc20
-    #[allow(clippy::too_many_arguments)]
-    fn swap(
+    fn unpause_pool(
         &mut self,
         token_0: Address,
         token_1: Address,
         fee: U128,
         tick_spacing: u32,
-        x_to_y: bool,
-        amount: U256,
-        by_amount_in: bool,
-        sqrt_price_limit: U256,
-    ) -> Result<CalculateSwapResult, InvariantError>;
+        kind: PauseKind,
+    ) -> Result<(), InvariantError>;
 
-    /// Simulates multiple swaps without its execution.
+    /// Retrieves operations paused in a single pool, not including operations paused globally.
     ///
     /// # Parameters
-    /// - `amount_in`: The amount of tokens that the user wants to swap.
-    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    fn get_pool_pause_status(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+    ) -> PauseStatus;
+
+    /// Extends the observation buffer of the pool, so the oracle can reach further into the past.
+    /// New slots are used after the buffer wraps around for the first time.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `cardinality`: The desired number of stored observations, ignored if not greater than the current one.
     ///
     /// # Errors
-    /// - Fails if the user attempts to perform a swap with zero amounts.
-    /// - Fails if the user would receive zero tokens.
     /// - Fails if pool does not exist
-    fn quote_route(
+    /// - Fails if the cardinality exceeds `MAX_ORACLE_CARDINALITY`
+    fn increase_observation_cardinality(
         &mut self,
-        amount_in: U256,
-        swaps: Vec<SwapHop>,
-    ) -> Result<TokenAmount, InvariantError>;
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        cardinality: u32,
+    ) -> Result<(), InvariantError>;
 
-    /// Performs atomic swap involving several pools based on the provided parameters.
+    /// Retrieves cumulative ticks of the pool at given moments in the past, along with time-weighted
+    /// average ticks and sqrt prices between consecutive moments.
+    /// Time is measured in milliseconds like the block time, so cumulative ticks grow by the tick every millisecond.
     ///
     /// # Parameters
-    /// - `amount_in`: The amount of tokens that the user wants to swap.
-    /// - `expected_amount_out`: The amount of tokens that the user wants to receive as a result of the swaps.
-    /// - `slippage`: The max acceptable percentage difference between the expected and actual amount of output tokens in a trade, not considering square root of target price as in the case of a swap.
-    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
-    ///
-    /// # Events
-    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
-    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `milliseconds_agos`: Strictly decreasing amounts of milliseconds before the current block time.
     ///
     /// # Errors
-    /// - Fails if the user attempts to perform a swap with zero amounts.
-    /// - Fails if the user would receive zero tokens.
-    /// - Fails if the allowance is insufficient or the user balance transfer fails.
-    /// - Fails if the minimum amount out after a single swap is insufficient to perform the next swap to achieve the expected amount out.
     /// - Fails if pool does not exist
+    /// - Fails if `milliseconds_agos` are not strictly decreasing
+    /// - Fails if any moment is older than the oldest stored observation
+    fn observe(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        milliseconds_agos: Vec<u64>,
+    ) -> Result<ObserveResult, InvariantError>;
+
+    /// Checks if the tick at a specified index is initialized.
     ///
-    /// # External contracts
-    /// - odra::Erc20
-    fn swap_route(
-        &mut self,
-        amount_in: U256,
-        expected_amount_out: U256,
-        slippage: U128,
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `index`: The tick index in the tickmap.
+    fn is_tick_initialized(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        index: i32,
+    ) -> bool;
+
+    /// Retrieves information about a tick at a specified index.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `index`: The tick index in the tickmap.
+    ///
+    /// # Errors
+    /// - Fails if tick cannot be found
+    fn get_tick(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        index: i32,
+    ) -> Result<Tick, InvariantError>;
+
+    /// Retrieves information about multiple ticks of a pool in a single call.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `tick_indexes`: Indexes of the ticks, returned in the same order.
+    ///
+    /// # Errors
+    /// - Fails if more than `LIQUIDITY_TICK_LIMIT` ticks are requested
+    /// - Fails if any of the ticks cannot be found
+    fn get_liquidity_ticks(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        tick_indexes: Vec<i32>,
+    ) -> Result<Vec<Tick>, InvariantError>;
+
+    /// Retrieves indexes of initialized ticks within a range by reading the tickmap chunks.
+    /// At most `TICKMAP_CHUNK_LIMIT` chunks are read per call, starting from the side the trade begins at.
+    /// Along with the indexes, returns the tick from which the rest of the range should be queried,
+    /// as `upper_tick` if `x_to_y` is `true` and as `lower_tick` otherwise, or `None` if the whole range was read.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `lower_tick`: The lower bound of the range, inclusive.
+    /// - `upper_tick`: The upper bound of the range, inclusive.
+    /// - `x_to_y`: The direction of the trade, indexes are descending if `true` and ascending otherwise.
+    ///
+    /// # Errors
+    /// - Fails if `lower_tick` is greater than `upper_tick`
+    /// - Fails if any of the bounds exceeds the tick range or is not divisible by `tick_spacing`
+    fn get_tickmap(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        x_to_y: bool,
+    ) -> Result<(Vec<i32>, Option<i32>), InvariantError>;
+
+    /// Allows an authorized user (owner of the position or an approved operator) to claim collected fees.
+    /// Claimed fees are always sent to the owner of the position. Fees of a staked position are claimed by and sent to the staker.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position from which fees will be claimed.
+    ///
+    /// # Events
+    /// - Emits a `Claim Fee` event upon success.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    /// - Fails if the caller is neither the owner of the position nor an approved operator, nor the staker of a staked position.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn claim_fee(&mut self, id: u64) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Opens a position. The returned position carries its id, which never changes
+    /// across removals of other positions or transfers.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `lower_tick`: The index of the lower tick for opening the position.
+    /// - `upper_tick`: The index of the upper tick for opening the position.
+    /// - `liquidity_delta`: The desired liquidity provided by the user in the specified range.
+    /// - `slippage_limit_lower`: The price limit for downward movement to execute the position creation.
+    /// - `slippage_limit_upper`: The price limit for upward movement to execute the position creation.
+    ///
+    /// # Events
+    /// - On successful transfer, emits a `Create Position` event for the newly opened position.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to open a position with zero liquidity.
+    /// - Fails if the user attempts to create a position with invalid tick indexes or tick spacing.
+    /// - Fails if the price has reached the slippage limit.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if pool does not exist
+    /// - Fails if positions are paused globally or in the pool.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn create_position(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+    ) -> Result<Position, InvariantError>;
+
+    /// Opens a position like `create_position`, but assigns it to the given owner. The tokens are still transferred from the caller.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `lower_tick`: The index of the lower tick for opening the position.
+    /// - `upper_tick`: The index of the upper tick for opening the position.
+    /// - `liquidity_delta`: The desired liquidity provided by the user in the specified range.
+    /// - `slippage_limit_lower`: The price limit for downward movement to execute the position creation.
+    /// - `slippage_limit_upper`: The price limit for upward movement to execute the position creation.
+    /// - `owner`: The address that will own the position.
+    ///
+    /// # Events
+    /// - On successful transfer, emits a `Create Position` event for the newly opened position.
+    ///
+    /// # Errors
+    /// - Fails in every case `create_position` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn create_position_with_owner(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+        owner: Address,
+    ) -> Result<Position, InvariantError>;
+
+    /// Opens a position with the maximal liquidity that can be provided with the desired token amounts.
+    /// The liquidity is computed at the current price of the pool.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `lower_tick`: The index of the lower tick for opening the position.
+    /// - `upper_tick`: The index of the upper tick for opening the position.
+    /// - `amount_x_desired`: The maximal amount of token x provided by the user.
+    /// - `amount_y_desired`: The maximal amount of token y provided by the user.
+    /// - `amount_x_min`: The minimal amount of token x that has to be provided.
+    /// - `amount_y_min`: The minimal amount of token y that has to be provided.
+    ///
+    /// # Events
+    /// - On successful transfer, emits a `Create Position` event for the newly opened position.
+    ///
+    /// # Errors
+    /// - Fails if the desired amounts result in zero liquidity.
+    /// - Fails if the user attempts to create a position with invalid tick indexes or tick spacing.
+    /// - Fails if the liquidity computed from the desired amounts overflows.
+    /// - Fails if the provided amounts exceed `amount_x_desired` or `amount_y_desired`.
+    /// - Fails if the provided amounts are below `amount_x_min` or `amount_y_min`.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if pool does not exist
+    /// - Fails if positions are paused globally or in the pool.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn create_position_by_amounts(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        amount_x_desired: U256,
+        amount_y_desired: U256,
+        amount_x_min: U256,
+        amount_y_min: U256,
+    ) -> Result<Position, InvariantError>;
+
+    /// Opens a position like `create_position` unless the deadline has passed.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `lower_tick`: The index of the lower tick for opening the position.
+    /// - `upper_tick`: The index of the upper tick for opening the position.
+    /// - `liquidity_delta`: The desired liquidity provided by the user in the specified range.
+    /// - `slippage_limit_lower`: The price limit for downward movement to execute the position creation.
+    /// - `slippage_limit_upper`: The price limit for upward movement to execute the position creation.
+    /// - `deadline_ms`: The last block time, in milliseconds since the Unix epoch, at which the position can be opened.
+    ///
+    /// # Errors
+    /// - Fails if the block time is past the deadline.
+    /// - Fails in every case `create_position` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn create_position_with_deadline(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+        deadline_ms: u64,
+    ) -> Result<Position, InvariantError>;
+
+    /// Adds liquidity to an existing position without changing its range.
+    /// Fees accumulated so far are stored in the position and can be claimed later.
+    /// Tokens are taken from the caller, which can be the owner or an approved operator.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position to which liquidity will be added.
+    /// - `liquidity_delta`: The additional liquidity provided by the user.
+    /// - `slippage_limit_lower`: The price limit for downward movement to execute the liquidity increase.
+    /// - `slippage_limit_upper`: The price limit for upward movement to execute the liquidity increase.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    /// - Fails if the caller is neither the owner of the position nor an approved operator.
+    /// - Fails if the user attempts to add zero liquidity.
+    /// - Fails if the price has reached the slippage limit.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if positions are paused globally or in the pool.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn increase_liquidity(
+        &mut self,
+        id: u64,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+    ) -> Result<Position, InvariantError>;
+
+    /// Withdraws part of the liquidity from an existing position. Can be called by the owner or an approved operator.
+    /// Sends withdrawn tokens together with all accumulated fees to the owner.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position from which liquidity will be withdrawn.
+    /// - `liquidity_delta`: The liquidity withdrawn by the user.
+    /// - `slippage_limit_lower`: The price limit for downward movement to execute the liquidity decrease.
+    /// - `slippage_limit_upper`: The price limit for upward movement to execute the liquidity decrease.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    /// - Fails if the caller is neither the owner of the position nor an approved operator.
+    /// - Fails if the user attempts to withdraw zero liquidity.
+    /// - Fails if the user attempts to withdraw all liquidity of the position, `remove_position` should be used instead.
+    /// - Fails if the price has reached the slippage limit.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn decrease_liquidity(
+        &mut self,
+        id: u64,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Transfers a position between users.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position to transfer.
+    /// - `receiver`: An `AccountId` identifying the user who will own the position.
+    ///
+    /// # Events
+    /// - Emits a `Transfer Position` event upon success.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    /// - Fails if the caller is not the owner of the position.
+    fn transfer_position(&mut self, id: u64, receiver: Address) -> Result<(), InvariantError>;
+
+    /// Approves an operator to claim fees, modify liquidity and remove a single position on behalf of the owner.
+    /// The approval is cleared when the position is transferred. Only one operator per position can be approved.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position.
+    /// - `operator`: An `Address` identifying the approved operator.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    /// - Fails if the caller is not the owner of the position.
+    fn approve_position(&mut self, id: u64, operator: Address) -> Result<(), InvariantError>;
+
+    /// Revokes the operator approved for a single position.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    /// - Fails if the caller is not the owner of the position.
+    fn revoke_position(&mut self, id: u64) -> Result<(), InvariantError>;
+
+    /// Approves an operator to manage all current and future positions of the caller.
+    ///
+    /// # Parameters
+    /// - `operator`: An `Address` identifying the approved operator.
+    fn approve_operator(&mut self, operator: Address);
+
+    /// Revokes the approval given to an operator with `approve_operator`.
+    ///
+    /// # Parameters
+    /// - `operator`: An `Address` identifying the operator.
+    fn revoke_operator(&mut self, operator: Address);
+
+    /// Checks if the operator is allowed to manage the position, either as its owner or through any of the approvals.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the position.
+    /// - `operator`: An `Address` identifying the operator.
+    ///
+    /// # Errors
+    /// - Fails if the position cannot be found.
+    fn is_approved(&self, id: u64, operator: Address) -> Result<bool, InvariantError>;
+
+    /// Removes a position. Can be called by the owner or an approved operator.
+    /// Sends tokens associated with specified position to the owner.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position to be removed.
+    ///
+    /// # Events
+    /// - Emits a `Remove Position` event upon success.
+    ///
+    /// # Errors
+    /// - Fails if Position cannot be found
+    /// - Fails if the caller is neither the owner of the position nor an approved operator.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn remove_position(&mut self, id: u64) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Removes a position like `remove_position` unless the deadline has passed.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position to be removed.
+    /// - `deadline_ms`: The last block time, in milliseconds since the Unix epoch, at which the position can be removed.
+    ///
+    /// # Errors
+    /// - Fails if the block time is past the deadline.
+    /// - Fails in every case `remove_position` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn remove_position_with_deadline(
+        &mut self,
+        id: u64,
+        deadline_ms: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Removes a position like `remove_position` and reverts if the withdrawn amounts are too low.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the user position to be removed.
+    /// - `amount_x_min`: The minimal amount of token x to be withdrawn.
+    /// - `amount_y_min`: The minimal amount of token y to be withdrawn.
+    ///
+    /// # Errors
+    /// - Fails if the withdrawn amounts are below `amount_x_min` or `amount_y_min`.
+    /// - Fails in every case `remove_position` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn remove_position_with_min_amounts(
+        &mut self,
+        id: u64,
+        amount_x_min: U256,
+        amount_y_min: U256,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Retrieves information about a single position.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the position.
+    ///
+    /// # Errors
+    /// - Fails if position cannot be found
+    fn get_position(&mut self, id: u64) -> Result<Position, InvariantError>;
+
+    /// Retrieves the owner of a single position.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the position.
+    ///
+    /// # Errors
+    /// - Fails if position cannot be found
+    fn get_position_owner(&mut self, id: u64) -> Result<Address, InvariantError>;
+
+    /// Retrieves ids of all positions held by the user.
+    ///
+    /// # Parameters
+    /// - 'owner': An `Address` identifying the user who owns the positions.
+    fn get_position_ids(&mut self, owner: Address) -> Vec<u64>;
+
+    /// Retrieves a vector containing all positions held by the user.
+    ///
+    /// # Parameters
+    /// - 'owner': An `Address` identifying the user who owns the positions.
+    fn get_all_positions(&mut self, owner: Address) -> Vec<Position>;
+
+    /// Retrieves a page of positions held by the user together with the total number of their positions.
+    ///
+    /// # Parameters
+    /// - 'owner': An `Address` identifying the user who owns the positions.
+    /// - `offset`: The index of the first position on the page.
+    /// - `limit`: The maximal number of positions on the page.
+    fn get_positions_paginated(
+        &self,
+        owner: Address,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<Position>, u32);
+
+    /// Retrieves the seconds per liquidity accumulated inside the range of the position.
+    /// The value grows only while the price stays in the range, so the difference between two
+    /// readings multiplied by the liquidity of the position gives its time in range.
+    ///
+    /// # Parameters
+    /// - `id`: The id of the position.
+    ///
+    /// # Errors
+    /// - Fails if position does not exist
+    fn get_seconds_per_liquidity_inside(
+        &self,
+        id: u64,
+    ) -> Result<SecondsPerLiquidity, InvariantError>;
+
+    /// Creates a liquidity mining incentive paying `reward` to positions staked in the pool,
+    /// in proportion to their liquidity multiplied by the time spent in range.
+    ///
+    /// # Parameters
+    /// - `reward_token`: The address of the token paid as the reward.
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `reward`: The total amount of the reward token distributed by the incentive.
+    /// - `start_timestamp`: The moment, in milliseconds since the Unix epoch, from which positions can be staked.
+    /// - `end_timestamp`: The moment, in milliseconds since the Unix epoch, when the incentive stops distributing rewards.
+    ///
+    /// # Errors
+    /// - Fails if pool does not exist
+    /// - Fails if the reward is zero
+    /// - Fails if the start is in the past or not before the end
+    /// - Fails if the user has insufficient balance or allowance of the reward token
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn create_incentive(
+        &mut self,
+        reward_token: Address,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        reward: U256,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Result<u64, InvariantError>;
+
+    /// Stakes a position in an active incentive. The contract holds the position until it is
+    /// unstaked, so its liquidity cannot change in the meantime, while the staker can still claim its fees.
+    /// A position can be staked in a single incentive at a time.
+    ///
+    /// # Parameters
+    /// - `incentive_id`: The id of the incentive.
+    /// - `position_id`: The id of the position.
+    ///
+    /// # Errors
+    /// - Fails if the caller is not the owner of the position
+    /// - Fails if incentive does not exist or is not active
+    /// - Fails if the position belongs to a different pool than the incentive
+    /// - Fails if the position is already staked
+    fn stake(&mut self, incentive_id: u64, position_id: u64) -> Result<(), InvariantError>;
+
+    /// Unstakes a position, returns it to its owner and credits the owner with the accrued reward.
+    /// After the end of the incentive anyone can unstake on behalf of the owner.
+    ///
+    /// # Parameters
+    /// - `position_id`: The id of the position.
+    ///
+    /// # Errors
+    /// - Fails if the position is not staked
+    /// - Fails if the caller is not the staker while the incentive has not ended
+    fn unstake(&mut self, position_id: u64) -> Result<TokenAmount, InvariantError>;
+
+    /// Transfers rewards in the given token credited to the caller by unstaking.
+    ///
+    /// # Parameters
+    /// - `reward_token`: The address of the reward token.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn claim_reward(&mut self, reward_token: Address) -> Result<TokenAmount, InvariantError>;
+
+    /// Transfers the rewards left in an ended incentive to its creator.
+    ///
+    /// # Parameters
+    /// - `incentive_id`: The id of the incentive.
+    ///
+    /// # Errors
+    /// - Fails if incentive does not exist
+    /// - Fails if the incentive has not ended or there are still positions staked in it
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn refund_incentive(&mut self, incentive_id: u64) -> Result<TokenAmount, InvariantError>;
+
+    /// Retrieves information about an incentive.
+    ///
+    /// # Parameters
+    /// - `incentive_id`: The id of the incentive.
+    ///
+    /// # Errors
+    /// - Fails if incentive does not exist
+    fn get_incentive(&self, incentive_id: u64) -> Result<Incentive, InvariantError>;
+
+    /// Retrieves information about a staked position.
+    ///
+    /// # Parameters
+    /// - `position_id`: The id of the position.
+    ///
+    /// # Errors
+    /// - Fails if the position is not staked
+    fn get_stake(&self, position_id: u64) -> Result<Stake, InvariantError>;
+
+    /// Retrieves the unclaimed reward of the user in the given token.
+    ///
+    /// # Parameters
+    /// - `owner`: An `Address` identifying the user.
+    /// - `reward_token`: The address of the reward token.
+    fn get_reward(&self, owner: Address, reward_token: Address) -> TokenAmount;
+
+    /// Places a limit order in the tick spacing wide range starting at `lower_tick`. Orders placed in the same range before it gets filled share a single position owned by the contract.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `lower_tick`: The index of the lower tick of the order range.
+    /// - `x_to_y`: A boolean specifying whether the order sells token x for token y.
+    /// - `liquidity_delta`: The amount of liquidity placed in the order.
+    ///
+    /// # Errors
+    /// - Fails if the pool does not exist
+    /// - Fails if the range is not entirely above the price when selling token x or entirely below it when selling token y
+    /// - Fails if positions are paused
+    /// - Fails if the user has insufficient balance or allowance
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn place_limit_order(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        x_to_y: bool,
+        liquidity_delta: U256,
+    ) -> Result<LimitOrder, InvariantError>;
+
+    /// Cancels a limit order that has not been filled yet and transfers its liquidity together with its share of earned fees to the caller.
+    ///
+    /// # Parameters
+    /// - `order_id`: The id of the limit order.
+    ///
+    /// # Errors
+    /// - Fails if the order does not exist
+    /// - Fails if the caller is not the owner of the order
+    /// - Fails if the order has been filled
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn cancel_limit_order(
+        &mut self,
+        order_id: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Transfers the proceeds of a filled limit order to the caller and removes the order.
+    ///
+    /// # Parameters
+    /// - `order_id`: The id of the limit order.
+    ///
+    /// # Errors
+    /// - Fails if the order does not exist
+    /// - Fails if the caller is not the owner of the order
+    /// - Fails if the order has not been filled yet
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn claim_limit_order(
+        &mut self,
+        order_id: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Retrieves information about a limit order.
+    ///
+    /// # Parameters
+    /// - `order_id`: The id of the limit order.
+    ///
+    /// # Errors
+    /// - Fails if the order does not exist
+    fn get_limit_order(&self, order_id: u64) -> Result<LimitOrder, InvariantError>;
+
+    /// Retrieves information about the range shared by limit orders, including whether it has been filled.
+    ///
+    /// # Parameters
+    /// - `epoch_id`: The id of the limit order epoch.
+    ///
+    /// # Errors
+    /// - Fails if the epoch does not exist
+    fn get_limit_order_epoch(&self, epoch_id: u64) -> Result<LimitOrderEpoch, InvariantError>;
+
+    /// Performs a swap that sends the output to the caller first and collects the input afterwards. The caller has to be a contract implementing `SwapCallback`, which is called with the owed input and has to approve it to the contract before returning.
+    /// The pool state is updated and the output is taken out of the pool reserves before the callback, so reentrant calls see the pool after the swap.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `data`: Arbitrary data passed to the callback.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the user inputs amount is zero
+    /// - Fails if the price has reached the slippage limit
+    /// - Fails if the pool does not exist or swaps are paused
+    /// - Fails if the input is not paid during the callback
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    /// - SwapCallback
+    #[allow(clippy::too_many_arguments)]
+    fn flash_swap(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        data: Vec<u8>,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Lends tokens of a pool to the receiver for the duration of a single call. The receiver is called back through `invariant_flash_callback` and has to approve the borrowed amounts increased by the pool fee to the contract, which are then pulled back. The fee is distributed between liquidity providers and the protocol like a swap fee, or goes to the protocol entirely when the pool has no active liquidity.
+    /// Only tokens deposited to the pool itself can be borrowed, not the ones of other pools holding the same tokens. Borrowed amounts are taken out of the pool reserves until they are repaid, so a nested call can't borrow them again.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `amount_x`: The amount of token x to borrow.
+    /// - `amount_y`: The amount of token y to borrow.
+    /// - `receiver`: The address of the contract implementing `FlashCallback`.
+    /// - `data`: Arbitrary data passed to the callback.
+    ///
+    /// # Events
+    /// - On successful repayment, emits a `Flash` event.
+    ///
+    /// # Errors
+    /// - Fails if both amounts are zero
+    /// - Fails if the pool does not exist or swaps are paused
+    /// - Fails if an amount exceeds the reserves of the pool
+    /// - Fails if the receiver does not return the borrowed amounts with the fee
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    /// - FlashCallback
+    #[allow(clippy::too_many_arguments)]
+    fn flash(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        amount_x: U256,
+        amount_y: U256,
+        receiver: Address,
+        data: Vec<u8>,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError>;
+
+    /// Executes several operations in a single deploy, in the given order. Every call is made on behalf of the caller, exactly as if the corresponding entrypoint was called directly.
+    ///
+    /// # Parameters
+    /// - `calls`: A vector of operations with their arguments.
+    ///
+    /// # Errors
+    /// - Fails if any of the calls fails, reverting all of them.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn multicall(&mut self, calls: Vec<Call>) -> Result<Vec<CallResult>, InvariantError>;
+
+    /// Simulates the swap without its execution.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: The amount of tokens that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the price has reached the specified limit.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    #[allow(clippy::too_many_arguments)]
+    fn quote(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U256,
+    ) -> Result<QuoteResult, InvariantError>;
+
+    /// Simulates a swap that stops at the price limit instead of failing when it is reached.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: The maximal amount of tokens that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit at which the swap stops.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    #[allow(clippy::too_many_arguments)]
+    fn quote_with_partial_fill(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+    ) -> Result<QuoteResult, InvariantError>;
+
+    /// Simulates a swap moving the pool price to the target square root of price.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `target_sqrt_price`: The square root of price the pool should end up at. The swap direction is derived from it.
+    /// - `max_amount_in`: The maximal amount of tokens, including fee, that the user is willing to swap.
+    ///
+    /// # Errors
+    /// - Fails if the target square root of price equals the current one or is out of bounds.
+    /// - Fails if reaching the target requires more than `max_amount_in`.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    fn quote_swap_to_price(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        target_sqrt_price: U128,
+        max_amount_in: U256,
+    ) -> Result<QuoteResult, InvariantError>;
+
+    /// Performs a single swap based on the provided parameters.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the price has reached the specified price limit (or price associated with specified square root of price).
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if there is insufficient liquidity in pool
+    /// - Fails if pool does not exist
+    /// - Fails if swaps are paused globally or in the pool.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn swap(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U256,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Performs a swap like `swap`, but sends the output tokens to the given recipient. The input tokens are still transferred from the caller.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `recipient`: The address receiving the output tokens.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails in every case `swap` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn swap_with_recipient(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        recipient: Address,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Performs a swap like `swap` unless the deadline has passed.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `deadline_ms`: The last block time, in milliseconds since the Unix epoch, at which the swap can be executed.
+    ///
+    /// # Errors
+    /// - Fails if the block time is past the deadline.
+    /// - Fails in every case `swap` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn swap_with_deadline(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        deadline_ms: u64,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Performs a swap like `swap_with_recipient` unless the deadline has passed.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `recipient`: The address receiving the output tokens.
+    /// - `deadline_ms`: The last block time, in milliseconds since the Unix epoch, at which the swap can be executed.
+    ///
+    /// # Errors
+    /// - Fails if the block time is past the deadline.
+    /// - Fails in every case `swap` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn swap_with_recipient_and_deadline(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        recipient: Address,
+        deadline_ms: u64,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Performs a swap like `swap` and reverts if the amount out or in is worse than expected.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: TokenAmount that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit allowing the price to move for the swap to occur.
+    /// - `expected_amount`: The expected amount out if `by_amount_in`, otherwise the expected amount in.
+    /// - `slippage`: The maximal deviation from `expected_amount` represented as a percentage.
+    ///
+    /// # Errors
+    /// - Fails if the amount out is below the minimal amount out when swapping by amount in.
+    /// - Fails if the amount in is above the maximal amount in when swapping by amount out.
+    /// - Fails in every case `swap` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn swap_with_slippage(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        expected_amount: U256,
+        slippage: U128,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Performs a swap like `swap`, but stops at the price limit instead of failing when it is reached. Only the consumed amount is transferred from the caller and reported in the result.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `x_to_y`: A boolean specifying the swap direction.
+    /// - `amount`: The maximal amount of tokens that the user wants to swap.
+    /// - `by_amount_in`: A boolean specifying whether the user provides the amount to swap or expects the amount out.
+    /// - `sqrt_price_limit`: A square root of price limit at which the swap stops.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails in every case `swap` does, except when the price limit is reached.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    #[allow(clippy::too_many_arguments)]
+    fn swap_with_partial_fill(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Swaps exactly the amount required to move the pool price to the target square root of price.
+    ///
+    /// # Parameters
+    /// - `token_0`: The address of the first token.
+    /// - `token_1`: The address of the second token.
+    /// - `fee`: A value identifying the pool fee determined in percentages.
+    /// - `tick_spacing`: The tick spacing for the specified fee tier.
+    /// - `target_sqrt_price`: The square root of price the pool should end up at. The swap direction is derived from it.
+    /// - `max_amount_in`: The maximal amount of tokens, including fee, that the user is willing to swap.
+    ///
+    /// # Events
+    /// - On a successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On a successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the target square root of price equals the current one or is out of bounds.
+    /// - Fails if reaching the target requires more than `max_amount_in`.
+    /// - Fails in every other case `swap` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_to_price(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        target_sqrt_price: U128,
+        max_amount_in: U256,
+    ) -> Result<CalculateSwapResult, InvariantError>;
+
+    /// Simulates multiple swaps without its execution.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    fn quote_route(
+        &mut self,
+        amount_in: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<TokenAmount, InvariantError>;
+
+    /// Simulates multiple swaps like `quote_route`, but returns the final amount out together with amounts, fee, prices and crossed ticks of every swap step.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if pool does not exist
+    fn quote_route_with_hops(
+        &mut self,
+        amount_in: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<QuoteRouteResult, InvariantError>;
+
+    /// Performs atomic swap involving several pools based on the provided parameters.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `expected_amount_out`: The amount of tokens that the user wants to receive as a result of the swaps.
+    /// - `slippage`: The max acceptable percentage difference between the expected and actual amount of output tokens in a trade, not considering square root of target price as in the case of a swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    ///
+    /// # Events
+    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the user would receive zero tokens.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if the minimum amount out after a single swap is insufficient to perform the next swap to achieve the expected amount out.
+    /// - Fails if pool does not exist
+    /// - Fails if swaps are paused globally or in any of the pools.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_route(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+    ) -> Result<(), InvariantError>;
+
+    /// Performs a swap route like `swap_route`, but sends the output of the last swap to the given recipient.
+    /// Outputs of the intermediate swaps stay with the caller to pay for the following ones.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `expected_amount_out`: The amount of tokens that the user wants to receive as a result of the swaps.
+    /// - `slippage`: The max acceptable percentage difference between the expected and actual amount of output tokens in a trade.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `recipient`: The address receiving the output tokens of the last swap.
+    ///
+    /// # Events
+    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails in every case `swap_route` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_route_with_recipient(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+        recipient: Address,
+    ) -> Result<(), InvariantError>;
+
+    /// Performs multiple swaps like `swap_route` unless the deadline has passed.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `expected_amount_out`: The amount of tokens that the user wants to receive as a result of the swaps.
+    /// - `slippage`: The max acceptable percentage difference between the expected and actual amount of output tokens in a trade, not considering square root of target price as in the case of a swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `deadline_ms`: The last block time, in milliseconds since the Unix epoch, at which the swaps can be executed.
+    ///
+    /// # Errors
+    /// - Fails if the block time is past the deadline.
+    /// - Fails in every case `swap_route` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_route_with_deadline(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+        deadline_ms: u64,
+    ) -> Result<(), InvariantError>;
+
+    /// Performs a swap route like `swap_route_with_recipient` unless the deadline has passed.
+    ///
+    /// # Parameters
+    /// - `amount_in`: The amount of tokens that the user wants to swap.
+    /// - `expected_amount_out`: The amount of tokens that the user wants to receive as a result of the swaps.
+    /// - `slippage`: The max acceptable percentage difference between the expected and actual amount of output tokens in a trade.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `recipient`: The address receiving the output tokens of the last swap.
+    /// - `deadline_ms`: The last block time, in milliseconds since the Unix epoch, at which the swaps can be executed.
+    ///
+    /// # Errors
+    /// - Fails if the block time is past the deadline.
+    /// - Fails in every case `swap_route` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_route_with_recipient_and_deadline(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+        recipient: Address,
+        deadline_ms: u64,
+    ) -> Result<(), InvariantError>;
+
+    /// Simulates multiple swaps specified by the final output amount without its execution.
+    /// Returns the amount of input tokens required by the first swap together with the breakdown of every swap step.
+    ///
+    /// # Parameters
+    /// - `amount_out`: The amount of tokens that the user wants to receive after the last swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if there is insufficient liquidity in pool to deliver the requested amount.
+    /// - Fails if the same pool appears in more than one swap step.
+    /// - Fails if pool does not exist
+    fn quote_route_exact_out(
+        &mut self,
+        amount_out: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<QuoteRouteResult, InvariantError>;
+
+    /// Performs atomic swap involving several pools that delivers an exact amount of output tokens.
+    /// Required inputs are calculated from the last swap to the first one, then swaps are executed in order.
+    ///
+    /// # Parameters
+    /// - `amount_out`: The amount of tokens that the user wants to receive after the last swap.
+    /// - `max_amount_in`: The maximum amount of tokens that the user is willing to spend on the first swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    ///
+    /// # Events
+    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails if the user attempts to perform a swap with zero amounts.
+    /// - Fails if the required amount in exceeds `max_amount_in`. This is checked before any swap is executed.
+    /// - Fails if the same pool appears in more than one swap step.
+    /// - Fails if the allowance is insufficient or the user balance transfer fails.
+    /// - Fails if pool does not exist
+    /// - Fails if swaps are paused globally or in any of the pools.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_route_exact_out(
+        &mut self,
+        amount_out: U256,
+        max_amount_in: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<(), InvariantError>;
+
+    /// Performs a swap route like `swap_route_exact_out`, but sends the output of the last swap to the given recipient.
+    /// Outputs of the intermediate swaps stay with the caller to pay for the following ones.
+    ///
+    /// # Parameters
+    /// - `amount_out`: The amount of tokens that the recipient receives after the last swap.
+    /// - `max_amount_in`: The maximum amount of tokens that the user is willing to spend on the first swap.
+    /// - `swaps`: A vector containing all parameters needed to identify separate swap steps.
+    /// - `recipient`: The address receiving the output tokens of the last swap.
+    ///
+    /// # Events
+    /// - On every successful swap, emits a `Swap` event for the freshly made swap.
+    /// - On every successful swap, emits a `Cross Tick` event for every single tick crossed.
+    ///
+    /// # Errors
+    /// - Fails in every case `swap_route_exact_out` does.
+    ///
+    /// # External contracts
+    /// - odra::Erc20
+    fn swap_route_exact_out_with_recipient(
+        &mut self,
+        amount_out: U256,
+        max_amount_in: U256,
         swaps: Vec<SwapHop>,
+        recipient: Address,
     ) -> Result<(), InvariantError>;
 }
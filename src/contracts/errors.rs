@@ -30,6 +30,30 @@ pub enum InvariantError {
     InvalidInitSqrtPrice,
     InvalidTickIndex,
     TickLimitReached,
+    InsufficientLiquidity,
+    AmountOverMaximumAmountIn,
+    NotPositionOwner,
+    NotPendingAdmin,
+    Paused,
+    ObservationTooOld,
+    InvalidObservationWindow,
+    IncentiveNotFound,
+    InvalidIncentiveTime,
+    IncentiveNotActive,
+    IncentiveNotEnded,
+    StakeNotFound,
+    PositionAlreadyStaked,
+    IncentivePoolMismatch,
+    LimitOrderNotFound,
+    InvalidLimitOrderTick,
+    LimitOrderNotFilled,
+    LimitOrderFilled,
+    DeadlineExceeded,
+    AmountBelowMinimum,
+    DuplicatedPoolInRoute,
+    TooManyTicks,
+    LiquidityOverflow,
+    InvalidOracleCardinality,
 }
 
 execution_error! {
@@ -62,6 +86,30 @@ execution_error! {
         InvalidInitSqrtPrice => 25,
         InvalidTickIndex => 26,
         TickLimitReached => 27,
+        InsufficientLiquidity => 28,
+        AmountOverMaximumAmountIn => 29,
+        NotPositionOwner => 30,
+        NotPendingAdmin => 31,
+        Paused => 32,
+        ObservationTooOld => 33,
+        InvalidObservationWindow => 34,
+        IncentiveNotFound => 35,
+        InvalidIncentiveTime => 36,
+        IncentiveNotActive => 37,
+        IncentiveNotEnded => 38,
+        StakeNotFound => 39,
+        PositionAlreadyStaked => 40,
+        IncentivePoolMismatch => 41,
+        LimitOrderNotFound => 42,
+        InvalidLimitOrderTick => 43,
+        LimitOrderNotFilled => 44,
+        LimitOrderFilled => 45,
+        DeadlineExceeded => 46,
+        AmountBelowMinimum => 47,
+        DuplicatedPoolInRoute => 48,
+        TooManyTicks => 49,
+        LiquidityOverflow => 50,
+        InvalidOracleCardinality => 51,
     }
 }
 
@@ -145,6 +193,76 @@ pub fn unwrap_invariant_result<T>(invariant_error: Result<T, InvariantError>) ->
             InvariantError::TickLimitReached => {
                 contract_env::revert(InvariantErrorReturn::TickLimitReached)
             }
+            InvariantError::InsufficientLiquidity => {
+                contract_env::revert(InvariantErrorReturn::InsufficientLiquidity)
+            }
+            InvariantError::AmountOverMaximumAmountIn => {
+                contract_env::revert(InvariantErrorReturn::AmountOverMaximumAmountIn)
+            }
+            InvariantError::NotPositionOwner => {
+                contract_env::revert(InvariantErrorReturn::NotPositionOwner)
+            }
+            InvariantError::NotPendingAdmin => {
+                contract_env::revert(InvariantErrorReturn::NotPendingAdmin)
+            }
+            InvariantError::Paused => contract_env::revert(InvariantErrorReturn::Paused),
+            InvariantError::ObservationTooOld => {
+                contract_env::revert(InvariantErrorReturn::ObservationTooOld)
+            }
+            InvariantError::InvalidObservationWindow => {
+                contract_env::revert(InvariantErrorReturn::InvalidObservationWindow)
+            }
+            InvariantError::IncentiveNotFound => {
+                contract_env::revert(InvariantErrorReturn::IncentiveNotFound)
+            }
+            InvariantError::InvalidIncentiveTime => {
+                contract_env::revert(InvariantErrorReturn::InvalidIncentiveTime)
+            }
+            InvariantError::IncentiveNotActive => {
+                contract_env::revert(InvariantErrorReturn::IncentiveNotActive)
+            }
+            InvariantError::IncentiveNotEnded => {
+                contract_env::revert(InvariantErrorReturn::IncentiveNotEnded)
+            }
+            InvariantError::StakeNotFound => {
+                contract_env::revert(InvariantErrorReturn::StakeNotFound)
+            }
+            InvariantError::PositionAlreadyStaked => {
+                contract_env::revert(InvariantErrorReturn::PositionAlreadyStaked)
+            }
+            InvariantError::IncentivePoolMismatch => {
+                contract_env::revert(InvariantErrorReturn::IncentivePoolMismatch)
+            }
+            InvariantError::LimitOrderNotFound => {
+                contract_env::revert(InvariantErrorReturn::LimitOrderNotFound)
+            }
+            InvariantError::InvalidLimitOrderTick => {
+                contract_env::revert(InvariantErrorReturn::InvalidLimitOrderTick)
+            }
+            InvariantError::LimitOrderNotFilled => {
+                contract_env::revert(InvariantErrorReturn::LimitOrderNotFilled)
+            }
+            InvariantError::LimitOrderFilled => {
+                contract_env::revert(InvariantErrorReturn::LimitOrderFilled)
+            }
+            InvariantError::DeadlineExceeded => {
+                contract_env::revert(InvariantErrorReturn::DeadlineExceeded)
+            }
+            InvariantError::AmountBelowMinimum => {
+                contract_env::revert(InvariantErrorReturn::AmountBelowMinimum)
+            }
+            InvariantError::DuplicatedPoolInRoute => {
+                contract_env::revert(InvariantErrorReturn::DuplicatedPoolInRoute)
+            }
+            InvariantError::TooManyTicks => {
+                contract_env::revert(InvariantErrorReturn::TooManyTicks)
+            }
+            InvariantError::LiquidityOverflow => {
+                contract_env::revert(InvariantErrorReturn::LiquidityOverflow)
+            }
+            InvariantError::InvalidOracleCardinality => {
+                contract_env::revert(InvariantErrorReturn::InvalidOracleCardinality)
+            }
         },
     }
 }
@@ -1,7 +1,9 @@
-use super::PoolKey;
-use crate::math::{liquidity::Liquidity, sqrt_price::SqrtPrice, token_amount::TokenAmount};
+use super::{FeeTier, PauseStatus, PoolKey, Role};
+use crate::math::{
+    liquidity::Liquidity, percentage::Percentage, sqrt_price::SqrtPrice, token_amount::TokenAmount,
+};
+use odra::types::Address;
 use odra::Event;
-use odra::{prelude::vec::Vec, types::Address};
 
 #[derive(Event, PartialEq, Eq, Debug)]
 pub struct CreatePositionEvent {
@@ -24,13 +26,15 @@ pub struct RemovePositionEvent {
     pub upper_tick: i32,
     pub current_sqrt_price: SqrtPrice,
 }
-#[derive(Event, PartialEq, Eq, Debug)]
 
+#[derive(Event, PartialEq, Eq, Debug)]
 pub struct CrossTickEvent {
     pub timestamp: u64,
     pub address: Address,
     pub pool: PoolKey,
-    pub indexes: Vec<i32>,
+    pub index: i32,
+    pub sign: bool,
+    pub liquidity_change: Liquidity,
 }
 
 #[derive(Event, PartialEq, Eq, Debug)]
@@ -44,4 +48,131 @@ pub struct SwapEvent {
     pub start_sqrt_price: SqrtPrice,
     pub target_sqrt_price: SqrtPrice,
     pub x_to_y: bool,
+    pub by_amount_in: bool,
+    pub liquidity: Liquidity,
+    pub current_tick_index: i32,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct FlashEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub receiver: Address,
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+    pub fee_x: TokenAmount,
+    pub fee_y: TokenAmount,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct CreatePoolEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub pool: PoolKey,
+    pub init_sqrt_price: SqrtPrice,
+    pub init_tick: i32,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct ClaimFeeEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub owner: Address,
+    pub position_id: u64,
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct TransferPositionEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub receiver: Address,
+    pub position_id: u64,
+    pub pool: PoolKey,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct WithdrawProtocolFeeEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub pool: PoolKey,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct AddFeeTierEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub fee_tier: FeeTier,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct RemoveFeeTierEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub fee_tier: FeeTier,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct ChangeProtocolFeeEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub protocol_fee: Percentage,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct ChangeFeeReceiverEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub pool: PoolKey,
+    pub fee_receiver: Address,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct ChangePauseStatusEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub pause_status: PauseStatus,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct ChangePoolPauseStatusEvent {
+    pub timestamp: u64,
+    pub address: Address,
+    pub pool: PoolKey,
+    pub pause_status: PauseStatus,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct AdminProposedEvent {
+    pub timestamp: u64,
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct AdminChangedEvent {
+    pub timestamp: u64,
+    pub previous_admin: Address,
+    pub admin: Address,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct RoleGrantedEvent {
+    pub timestamp: u64,
+    pub role: Role,
+    pub account: Address,
+    pub sender: Address,
+}
+
+#[derive(Event, PartialEq, Eq, Debug)]
+pub struct RoleRevokedEvent {
+    pub timestamp: u64,
+    pub role: Role,
+    pub account: Address,
+    pub sender: Address,
 }
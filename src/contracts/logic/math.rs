@@ -93,6 +93,69 @@ pub fn get_liquidity(
     })
 }
 
+// Maximal liquidity that can be provided with at most `x` and `y`. The range is resolved by the current tick index
+// the same way amounts of a position are, so the price resting on the lower tick does not divide by zero.
+pub fn get_max_liquidity(
+    x: TokenAmount,
+    y: TokenAmount,
+    lower_tick: i32,
+    upper_tick: i32,
+    current_tick_index: i32,
+    current_sqrt_price: SqrtPrice,
+) -> TrackableResult<Liquidity> {
+    if lower_tick >= upper_tick {
+        return Err(err!("Invalid Ticks"));
+    }
+
+    if current_tick_index < lower_tick {
+        let result_by_x = ok_or_mark_trace!(get_liquidity_by_x(
+            x,
+            lower_tick,
+            upper_tick,
+            current_sqrt_price,
+            false
+        ))?;
+        return Ok(result_by_x.l);
+    }
+
+    if current_tick_index >= upper_tick {
+        let result_by_y = ok_or_mark_trace!(get_liquidity_by_y(
+            y,
+            lower_tick,
+            upper_tick,
+            current_sqrt_price,
+            false
+        ))?;
+        return Ok(result_by_y.l);
+    }
+
+    let result_by_x = ok_or_mark_trace!(get_liquidity_by_x(
+        x,
+        lower_tick,
+        upper_tick,
+        current_sqrt_price,
+        false
+    ))?;
+
+    if current_sqrt_price == ok_or_mark_trace!(calculate_sqrt_price(lower_tick))? {
+        return Ok(result_by_x.l);
+    }
+
+    let result_by_y = ok_or_mark_trace!(get_liquidity_by_y(
+        y,
+        lower_tick,
+        upper_tick,
+        current_sqrt_price,
+        false
+    ))?;
+
+    Ok(if result_by_y.l < result_by_x.l {
+        result_by_y.l
+    } else {
+        result_by_x.l
+    })
+}
+
 #[allow(dead_code)]
 pub fn get_liquidity_by_x(
     x: TokenAmount,
@@ -398,6 +461,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn get_max_liquidity_test() {
+        let x = TokenAmount::new(U256::from(43_0000));
+        let y = TokenAmount::new(U256::from(47_600_000_000u64));
+        // below current tick
+        {
+            let current_sqrt_price = calculate_sqrt_price(-20000).unwrap();
+            let result = get_max_liquidity(x, y, -22000, -21000, -20000, current_sqrt_price);
+            assert_eq!(
+                result.unwrap(),
+                Liquidity::new(U256::from(278905227910392327u64))
+            );
+        }
+        // in current tick
+        {
+            let current_sqrt_price = calculate_sqrt_price(100).unwrap();
+            let by_x = get_liquidity_by_x(x, 80, 120, current_sqrt_price, false)
+                .unwrap()
+                .l;
+            let result = get_max_liquidity(x, y, 80, 120, 100, current_sqrt_price);
+            assert_eq!(result.unwrap(), by_x);
+
+            let y = TokenAmount::new(U256::from(1000));
+            let by_y = get_liquidity_by_y(y, 80, 120, current_sqrt_price, false)
+                .unwrap()
+                .l;
+            let result = get_max_liquidity(x, y, 80, 120, 100, current_sqrt_price);
+            assert_eq!(result.unwrap(), by_y);
+        }
+        // current price on the lower tick
+        {
+            let current_sqrt_price = calculate_sqrt_price(80).unwrap();
+            let by_x = get_liquidity_by_x(x, 80, 120, current_sqrt_price, false)
+                .unwrap()
+                .l;
+            let result = get_max_liquidity(x, y, 80, 120, 80, current_sqrt_price);
+            assert_eq!(result.unwrap(), by_x);
+        }
+        // above current tick
+        {
+            let current_sqrt_price = calculate_sqrt_price(100).unwrap();
+            let result = get_max_liquidity(x, y, 150, 800, 100, current_sqrt_price);
+            assert_eq!(
+                result.unwrap(),
+                Liquidity::new(U256::from(1354882631162u64))
+            );
+        }
+        // invalid ticks
+        {
+            let current_sqrt_price = calculate_sqrt_price(100).unwrap();
+            let result = get_max_liquidity(x, y, 120, 120, 100, current_sqrt_price);
+            assert!(result.is_err());
+        }
+    }
+
     #[test]
     fn get_liquidity_test() {
         let y = TokenAmount::new(U256::from(47_600_000_000u64));
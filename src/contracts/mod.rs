@@ -1,13 +1,17 @@
+pub mod callbacks;
 pub mod collections;
 pub mod entrypoints;
 pub mod errors;
 pub mod events;
 pub mod logic;
+pub mod multicall;
 pub mod storage;
 
+pub use callbacks::*;
 pub use collections::*;
 pub use entrypoints::*;
 pub use errors::*;
 pub use events::*;
 pub use logic::*;
+pub use multicall::*;
 pub use storage::*;
@@ -0,0 +1,190 @@
+use super::Position;
+use crate::math::token_amount::TokenAmount;
+use crate::{CalculateSwapResult, SwapHop};
+use odra::prelude::vec::Vec;
+use odra::types::casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use odra::types::casper_types::{CLType, CLTyped};
+use odra::types::{Address, U128, U256};
+
+// `OdraType` can't be derived for enums carrying data, variants are encoded as a tag followed by their fields
+macro_rules! impl_tagged_bytesrepr {
+    ($name:ident { $($tag:literal => $variant:ident $(($($field:ident: $ty:ty),+))?),+ $(,)? }) => {
+        impl CLTyped for $name {
+            fn cl_type() -> CLType {
+                CLType::Any
+            }
+        }
+
+        impl ToBytes for $name {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                let mut buffer = bytesrepr::allocate_buffer(self)?;
+                match self {
+                    $($name::$variant $(($($field),+))? => {
+                        buffer.push($tag);
+                        $($(buffer.extend($field.to_bytes()?);)+)?
+                    })+
+                }
+                Ok(buffer)
+            }
+
+            fn serialized_length(&self) -> usize {
+                bytesrepr::U8_SERIALIZED_LENGTH
+                    + match self {
+                        $($name::$variant $(($($field),+))? => 0 $($(+ $field.serialized_length())+)?,)+
+                    }
+            }
+        }
+
+        impl FromBytes for $name {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let (tag, bytes) = u8::from_bytes(bytes)?;
+                match tag {
+                    $($tag => {
+                        $($(let ($field, bytes) = <$ty>::from_bytes(bytes)?;)+)?
+                        Ok(($name::$variant $(($($field),+))?, bytes))
+                    })+
+                    _ => Err(bytesrepr::Error::Formatting),
+                }
+            }
+        }
+    };
+}
+
+// every variant holds the arguments of the entrypoint it calls in the same order
+#[derive(Debug, Clone)]
+pub enum Call {
+    Swap(Address, Address, U128, u32, bool, U256, bool, U128),
+    SwapRoute(U256, U256, U128, Vec<SwapHop>),
+    CreatePosition(Address, Address, U128, u32, i32, i32, U256, U128, U128),
+    IncreaseLiquidity(u64, U256, U128, U128),
+    DecreaseLiquidity(u64, U256, U128, U128),
+    RemovePosition(u64),
+    ClaimFee(u64),
+    TransferPosition(u64, Address),
+}
+
+impl_tagged_bytesrepr!(Call {
+    0 => Swap(
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128
+    ),
+    1 => SwapRoute(
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>
+    ),
+    2 => CreatePosition(
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128
+    ),
+    3 => IncreaseLiquidity(
+        id: u64,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128
+    ),
+    4 => DecreaseLiquidity(
+        id: u64,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128
+    ),
+    5 => RemovePosition(id: u64),
+    6 => ClaimFee(id: u64),
+    7 => TransferPosition(id: u64, receiver: Address),
+});
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CallResult {
+    Swap(CalculateSwapResult),
+    SwapRoute,
+    CreatePosition(Position),
+    IncreaseLiquidity(Position),
+    DecreaseLiquidity(TokenAmount, TokenAmount),
+    RemovePosition(TokenAmount, TokenAmount),
+    ClaimFee(TokenAmount, TokenAmount),
+    TransferPosition,
+}
+
+impl_tagged_bytesrepr!(CallResult {
+    0 => Swap(result: CalculateSwapResult),
+    1 => SwapRoute,
+    2 => CreatePosition(position: Position),
+    3 => IncreaseLiquidity(position: Position),
+    4 => DecreaseLiquidity(amount_x: TokenAmount, amount_y: TokenAmount),
+    5 => RemovePosition(amount_x: TokenAmount, amount_y: TokenAmount),
+    6 => ClaimFee(amount_x: TokenAmount, amount_y: TokenAmount),
+    7 => TransferPosition,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decimal::*;
+    use odra::prelude::vec;
+    use odra::types::casper_types::account::AccountHash;
+
+    #[test]
+    fn test_call_bytesrepr() {
+        let address = Address::Account(AccountHash::new([0x01; 32]));
+        let calls = vec![
+            Call::ClaimFee(3),
+            Call::TransferPosition(1, address),
+            Call::SwapRoute(
+                U256::from(10),
+                U256::from(9),
+                U128::from(1),
+                vec![SwapHop {
+                    token_x: address,
+                    token_y: address,
+                    fee: U128::from(6),
+                    tick_spacing: 10,
+                    x_to_y: true,
+                }],
+            ),
+        ];
+
+        for call in calls {
+            let bytes = call.to_bytes().unwrap();
+            assert_eq!(bytes.len(), call.serialized_length());
+
+            let (decoded, remainder) = Call::from_bytes(&bytes).unwrap();
+            assert!(remainder.is_empty());
+            assert_eq!(decoded.to_bytes().unwrap(), bytes);
+        }
+
+        assert_eq!(
+            Call::from_bytes(&[8]).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+
+    #[test]
+    fn test_call_result_bytesrepr() {
+        let results = vec![
+            CallResult::SwapRoute,
+            CallResult::CreatePosition(Position::default()),
+            CallResult::ClaimFee(TokenAmount::from_integer(1), TokenAmount::from_integer(2)),
+        ];
+
+        for result in results {
+            let bytes = result.to_bytes().unwrap();
+            assert_eq!(bytes.len(), result.serialized_length());
+            assert_eq!(CallResult::from_bytes(&bytes).unwrap(), (result, &[][..]));
+        }
+    }
+}
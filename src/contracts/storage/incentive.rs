@@ -0,0 +1,192 @@
+use super::PoolKey;
+use crate::math::{
+    liquidity::Liquidity, seconds_per_liquidity::SecondsPerLiquidity, token_amount::TokenAmount,
+    MILLISECONDS_PER_SECOND,
+};
+use decimal::*;
+use odra::types::{Address, U256};
+use odra::OdraType;
+use traceable_result::*;
+
+#[derive(OdraType, Debug, PartialEq, Eq, Copy)]
+pub struct Incentive {
+    pub id: u64,
+    pub reward_token: Address,
+    pub pool_key: PoolKey,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub refundee: Address,
+    pub total_reward_unclaimed: TokenAmount,
+    // scaled by `SecondsPerLiquidity::one()`
+    pub total_seconds_claimed: U256,
+    pub number_of_stakes: u32,
+}
+
+#[derive(OdraType, Debug, PartialEq, Eq, Copy)]
+pub struct Stake {
+    pub incentive_id: u64,
+    pub owner: Address,
+    pub liquidity: Liquidity,
+    pub seconds_per_liquidity_inside_initial: SecondsPerLiquidity,
+}
+
+impl Incentive {
+    pub fn is_active(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.start_timestamp && current_timestamp < self.end_timestamp
+    }
+
+    pub fn is_ended(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.end_timestamp
+    }
+
+    // returns the reward of the stake and its seconds in range scaled by `SecondsPerLiquidity::one()`
+    pub fn calculate_reward(
+        &self,
+        stake: &Stake,
+        seconds_per_liquidity_inside: SecondsPerLiquidity,
+        current_timestamp: u64,
+    ) -> TrackableResult<(TokenAmount, U256)> {
+        let seconds_scale = U256::from(SecondsPerLiquidity::one().get().as_u128());
+
+        // seconds after the end dilute the reward, what is left can be refunded
+        // timestamps are in milliseconds, seconds are counted the same way as by the pool
+        let total_seconds = U256::from(
+            (self.end_timestamp.max(current_timestamp) / MILLISECONDS_PER_SECOND)
+                .checked_sub(self.start_timestamp / MILLISECONDS_PER_SECOND)
+                .ok_or_else(|| err!("current_timestamp - start_timestamp underflow"))?,
+        );
+        let total_seconds_unclaimed = total_seconds
+            .checked_mul(seconds_scale)
+            .ok_or_else(|| err!(TrackableError::MUL))?
+            .checked_sub(self.total_seconds_claimed)
+            .ok_or_else(|| err!(TrackableError::SUB))?;
+
+        let seconds_per_liquidity_delta =
+            seconds_per_liquidity_inside.unchecked_sub(stake.seconds_per_liquidity_inside_initial);
+        let seconds_inside = U256::from(seconds_per_liquidity_delta.get().as_u128())
+            .checked_mul(stake.liquidity.get())
+            .ok_or_else(|| err!(TrackableError::MUL))?
+            .checked_div(Liquidity::one().get())
+            .ok_or_else(|| err!(TrackableError::DIV))?;
+
+        if total_seconds_unclaimed.is_zero() {
+            return Ok((TokenAmount::new(U256::from(0)), U256::from(0)));
+        }
+
+        let reward = self
+            .total_reward_unclaimed
+            .get()
+            .checked_mul(seconds_inside)
+            .ok_or_else(|| err!(TrackableError::MUL))?
+            .checked_div(total_seconds_unclaimed)
+            .ok_or_else(|| err!(TrackableError::DIV))?;
+
+        Ok((TokenAmount::new(reward), seconds_inside))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use odra::types::casper_types::account::AccountHash;
+    use odra::types::U128;
+
+    fn incentive() -> Incentive {
+        let address = Address::Account(AccountHash::new([0x01; 32]));
+
+        Incentive {
+            id: 0,
+            reward_token: address,
+            pool_key: PoolKey::default(),
+            start_timestamp: 100_000,
+            end_timestamp: 200_000,
+            refundee: address,
+            total_reward_unclaimed: TokenAmount::from_integer(1000),
+            total_seconds_claimed: U256::from(0),
+            number_of_stakes: 1,
+        }
+    }
+
+    fn stake(liquidity: Liquidity) -> Stake {
+        Stake {
+            incentive_id: 0,
+            owner: Address::Account(AccountHash::new([0x02; 32])),
+            liquidity,
+            seconds_per_liquidity_inside_initial: SecondsPerLiquidity::new(U128::from(0)),
+        }
+    }
+
+    #[test]
+    fn test_is_active() {
+        let incentive = incentive();
+
+        assert!(!incentive.is_active(99_999));
+        assert!(incentive.is_active(100_000));
+        assert!(incentive.is_active(199_999));
+        assert!(!incentive.is_active(200_000));
+        assert!(!incentive.is_ended(199_999));
+        assert!(incentive.is_ended(200_000));
+    }
+
+    #[test]
+    fn test_calculate_reward() {
+        // only stake in range for the whole incentive
+        {
+            let incentive = incentive();
+            let stake = stake(Liquidity::from_integer(10));
+            // 100 seconds over 10 of liquidity
+            let seconds_per_liquidity_inside = SecondsPerLiquidity::from_integer(10);
+
+            let (reward, seconds_inside) = incentive
+                .calculate_reward(&stake, seconds_per_liquidity_inside, 200_000)
+                .unwrap();
+
+            assert_eq!(reward, TokenAmount::from_integer(1000));
+            assert_eq!(
+                seconds_inside,
+                U256::from(100) * U256::from(SecondsPerLiquidity::one().get().as_u128())
+            );
+        }
+        // half of the time in range
+        {
+            let incentive = incentive();
+            let stake = stake(Liquidity::from_integer(10));
+            let seconds_per_liquidity_inside = SecondsPerLiquidity::from_integer(5);
+
+            let (reward, _) = incentive
+                .calculate_reward(&stake, seconds_per_liquidity_inside, 150_000)
+                .unwrap();
+
+            assert_eq!(reward, TokenAmount::from_integer(500));
+        }
+        // late unstake dilutes the reward
+        {
+            let incentive = incentive();
+            let stake = stake(Liquidity::from_integer(10));
+            let seconds_per_liquidity_inside = SecondsPerLiquidity::from_integer(10);
+
+            let (reward, _) = incentive
+                .calculate_reward(&stake, seconds_per_liquidity_inside, 400_000)
+                .unwrap();
+
+            assert_eq!(reward, TokenAmount::from_integer(333));
+        }
+        // part of seconds already claimed
+        {
+            let incentive = Incentive {
+                total_reward_unclaimed: TokenAmount::from_integer(600),
+                total_seconds_claimed: U256::from(40)
+                    * U256::from(SecondsPerLiquidity::one().get().as_u128()),
+                ..incentive()
+            };
+            let stake = stake(Liquidity::from_integer(10));
+            let seconds_per_liquidity_inside = SecondsPerLiquidity::from_integer(6);
+
+            let (reward, _) = incentive
+                .calculate_reward(&stake, seconds_per_liquidity_inside, 200_000)
+                .unwrap();
+
+            assert_eq!(reward, TokenAmount::from_integer(600));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::PauseStatus;
 use crate::math::percentage::Percentage;
 use odra::types::Address;
 
@@ -6,5 +7,7 @@ use odra::OdraType;
 #[derive(OdraType)]
 pub struct InvariantConfig {
     pub admin: Address,
+    pub pending_admin: Option<Address>,
     pub protocol_fee: Percentage,
+    pub pause_status: PauseStatus,
 }
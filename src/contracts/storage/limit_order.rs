@@ -0,0 +1,126 @@
+use super::PoolKey;
+use crate::math::{liquidity::Liquidity, token_amount::TokenAmount};
+use decimal::*;
+use odra::types::{Address, U256};
+use odra::OdraType;
+use traceable_result::*;
+
+// liquidity of all orders placed in the same range until it gets filled, held in a single position
+#[derive(OdraType, Debug, PartialEq, Eq, Copy)]
+pub struct LimitOrderEpoch {
+    pub id: u64,
+    pub pool_key: PoolKey,
+    pub lower_tick_index: i32,
+    pub x_to_y: bool,
+    pub position_id: u64,
+    pub liquidity: Liquidity,
+    pub filled: bool,
+    pub amount_x: TokenAmount,
+    pub amount_y: TokenAmount,
+}
+
+#[derive(OdraType, Debug, PartialEq, Eq, Copy)]
+pub struct LimitOrder {
+    pub id: u64,
+    pub owner: Address,
+    pub epoch_id: u64,
+    pub liquidity: Liquidity,
+}
+
+impl LimitOrderEpoch {
+    // pays out the part of collected amounts owned by the liquidity and removes it from the epoch
+    pub fn withdraw_share(
+        &mut self,
+        liquidity: Liquidity,
+    ) -> TrackableResult<(TokenAmount, TokenAmount)> {
+        if liquidity > self.liquidity {
+            return Err(err!("liquidity > epoch liquidity"));
+        }
+
+        let (share_x, share_y) = if liquidity == self.liquidity {
+            (self.amount_x, self.amount_y)
+        } else {
+            (
+                Self::calculate_share(self.amount_x, liquidity, self.liquidity)?,
+                Self::calculate_share(self.amount_y, liquidity, self.liquidity)?,
+            )
+        };
+
+        self.amount_x -= share_x;
+        self.amount_y -= share_y;
+        self.liquidity -= liquidity;
+
+        Ok((share_x, share_y))
+    }
+
+    fn calculate_share(
+        amount: TokenAmount,
+        liquidity: Liquidity,
+        total_liquidity: Liquidity,
+    ) -> TrackableResult<TokenAmount> {
+        let share = amount
+            .get()
+            .checked_mul(liquidity.get())
+            .ok_or_else(|| err!(TrackableError::MUL))?
+            .checked_div(total_liquidity.get())
+            .ok_or_else(|| err!(TrackableError::DIV))?;
+
+        Ok(TokenAmount::new(share))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch() -> LimitOrderEpoch {
+        LimitOrderEpoch {
+            id: 0,
+            pool_key: PoolKey::default(),
+            lower_tick_index: 10,
+            x_to_y: true,
+            position_id: 0,
+            liquidity: Liquidity::from_integer(30),
+            filled: true,
+            amount_x: TokenAmount::new(U256::from(0)),
+            amount_y: TokenAmount::from_integer(100),
+        }
+    }
+
+    #[test]
+    fn test_withdraw_share() {
+        // pro-rata share rounded down
+        {
+            let mut epoch = epoch();
+
+            let (x, y) = epoch.withdraw_share(Liquidity::from_integer(10)).unwrap();
+
+            assert_eq!(x, TokenAmount::new(U256::from(0)));
+            assert_eq!(y, TokenAmount::from_integer(33));
+            assert_eq!(epoch.liquidity, Liquidity::from_integer(20));
+            assert_eq!(epoch.amount_y, TokenAmount::from_integer(67));
+        }
+        // last share takes the remainder
+        {
+            let mut epoch = LimitOrderEpoch {
+                liquidity: Liquidity::from_integer(20),
+                amount_y: TokenAmount::from_integer(67),
+                ..epoch()
+            };
+
+            let (_, y) = epoch.withdraw_share(Liquidity::from_integer(20)).unwrap();
+
+            assert_eq!(y, TokenAmount::from_integer(67));
+            assert!(epoch.liquidity.is_zero());
+            assert!(epoch.amount_y.is_zero());
+        }
+        // more than the epoch holds
+        {
+            let mut epoch = epoch();
+
+            let result = epoch.withdraw_share(Liquidity::from_integer(31));
+
+            assert!(result.is_err());
+        }
+    }
+}
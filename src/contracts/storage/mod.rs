@@ -1,15 +1,25 @@
 pub mod fee_tier;
+pub mod incentive;
 pub mod invariant_config;
+pub mod limit_order;
+pub mod oracle;
+pub mod pause_status;
 pub mod pool;
 pub mod pool_key;
 pub mod position;
+pub mod role;
 pub mod tick;
 pub mod tickmap;
 
 pub use fee_tier::*;
+pub use incentive::*;
 pub use invariant_config::*;
+pub use limit_order::*;
+pub use oracle::*;
+pub use pause_status::*;
 pub use pool::*;
 pub use pool_key::*;
 pub use position::*;
+pub use role::*;
 pub use tick::*;
 pub use tickmap::*;
@@ -0,0 +1,135 @@
+use odra::OdraType;
+
+#[derive(OdraType, Debug, Default, PartialEq, Eq, Copy)]
+pub struct Observation {
+    pub timestamp: u64,
+    pub tick_cumulative: i64,
+    pub initialized: bool,
+}
+
+#[derive(OdraType, Debug, Default, PartialEq, Eq, Copy)]
+pub struct Oracle {
+    pub index: u32,
+    pub cardinality: u32,
+    pub cardinality_next: u32,
+}
+
+impl Observation {
+    pub fn create(timestamp: u64) -> Self {
+        Self {
+            timestamp,
+            tick_cumulative: 0,
+            initialized: true,
+        }
+    }
+
+    // tick is the current tick of the pool, constant since the last observation
+    pub fn transform(&self, timestamp: u64, tick: i32) -> Self {
+        let delta = timestamp.wrapping_sub(self.timestamp) as i64;
+
+        Self {
+            timestamp,
+            tick_cumulative: self
+                .tick_cumulative
+                .wrapping_add((tick as i64).wrapping_mul(delta)),
+            initialized: true,
+        }
+    }
+
+    pub fn interpolate(&self, next: &Observation, target: u64) -> i64 {
+        let observation_time_delta = (next.timestamp - self.timestamp) as i64;
+        let target_delta = (target - self.timestamp) as i64;
+
+        self.tick_cumulative
+            + (next.tick_cumulative - self.tick_cumulative) / observation_time_delta * target_delta
+    }
+}
+
+impl Oracle {
+    pub fn create() -> Self {
+        Self {
+            index: 0,
+            cardinality: 1,
+            cardinality_next: 1,
+        }
+    }
+
+    pub fn next_index(&self) -> (u32, u32) {
+        let cardinality =
+            if self.cardinality_next > self.cardinality && self.index == self.cardinality - 1 {
+                self.cardinality_next
+            } else {
+                self.cardinality
+            };
+
+        ((self.index + 1) % cardinality, cardinality)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        let observation = Observation::create(10);
+
+        let result = observation.transform(15, -3);
+        assert_eq!(
+            result,
+            Observation {
+                timestamp: 15,
+                tick_cumulative: -15,
+                initialized: true,
+            }
+        );
+
+        let result = result.transform(20, 7);
+        assert_eq!(result.tick_cumulative, 20);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let before = Observation::create(10);
+        let after = before.transform(20, 4);
+
+        assert_eq!(before.interpolate(&after, 10), 0);
+        assert_eq!(before.interpolate(&after, 15), 20);
+        assert_eq!(before.interpolate(&after, 20), 40);
+    }
+
+    #[test]
+    fn test_next_index() {
+        let oracle = Oracle::create();
+        assert_eq!(oracle.next_index(), (0, 1));
+
+        // buffer grows only after the last slot is written
+        let oracle = Oracle {
+            index: 0,
+            cardinality: 1,
+            cardinality_next: 3,
+        };
+        assert_eq!(oracle.next_index(), (1, 3));
+
+        let oracle = Oracle {
+            index: 1,
+            cardinality: 3,
+            cardinality_next: 3,
+        };
+        assert_eq!(oracle.next_index(), (2, 3));
+
+        let oracle = Oracle {
+            index: 2,
+            cardinality: 3,
+            cardinality_next: 3,
+        };
+        assert_eq!(oracle.next_index(), (0, 3));
+
+        let oracle = Oracle {
+            index: 0,
+            cardinality: 3,
+            cardinality_next: 5,
+        };
+        assert_eq!(oracle.next_index(), (1, 3));
+    }
+}
@@ -0,0 +1,63 @@
+use odra::OdraType;
+
+#[derive(OdraType, Debug, PartialEq, Eq, Copy)]
+pub enum PauseKind {
+    Swaps,
+    Positions,
+    All,
+}
+
+#[derive(OdraType, Debug, Default, PartialEq, Eq, Copy)]
+pub struct PauseStatus {
+    pub swaps: bool,
+    pub positions: bool,
+}
+
+impl PauseStatus {
+    pub fn set(&mut self, kind: PauseKind, paused: bool) {
+        match kind {
+            PauseKind::Swaps => self.swaps = paused,
+            PauseKind::Positions => self.positions = paused,
+            PauseKind::All => {
+                self.swaps = paused;
+                self.positions = paused;
+            }
+        }
+    }
+
+    pub fn is_paused(&self, kind: PauseKind) -> bool {
+        match kind {
+            PauseKind::Swaps => self.swaps,
+            PauseKind::Positions => self.positions,
+            PauseKind::All => self.swaps && self.positions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let mut status = PauseStatus::default();
+        assert!(!status.is_paused(PauseKind::Swaps));
+        assert!(!status.is_paused(PauseKind::Positions));
+
+        status.set(PauseKind::Swaps, true);
+        assert!(status.is_paused(PauseKind::Swaps));
+        assert!(!status.is_paused(PauseKind::Positions));
+        assert!(!status.is_paused(PauseKind::All));
+
+        status.set(PauseKind::All, true);
+        assert!(status.is_paused(PauseKind::Positions));
+        assert!(status.is_paused(PauseKind::All));
+
+        status.set(PauseKind::Positions, false);
+        assert!(status.is_paused(PauseKind::Swaps));
+        assert!(!status.is_paused(PauseKind::All));
+
+        status.set(PauseKind::All, false);
+        assert_eq!(status, PauseStatus::default());
+    }
+}
@@ -1,13 +1,14 @@
 use super::{FeeTier, PoolKey, Tick};
 use crate::math::sqrt_price::get_max_tick;
-use crate::math::MAX_TICK;
+use crate::math::{MAX_TICK, MILLISECONDS_PER_SECOND};
 use crate::SwapResult;
 use crate::{
     contracts::InvariantError,
     math::{
         calculate_amount_delta, fee_growth::FeeGrowth, is_enough_amount_to_change_price,
         liquidity::Liquidity, log::get_tick_at_sqrt_price, percentage::Percentage,
-        sqrt_price::calculate_sqrt_price, sqrt_price::SqrtPrice, token_amount::TokenAmount,
+        seconds_per_liquidity::SecondsPerLiquidity, sqrt_price::calculate_sqrt_price,
+        sqrt_price::SqrtPrice, token_amount::TokenAmount,
     },
 };
 use decimal::*;
@@ -22,6 +23,7 @@ pub struct Pool {
     pub current_tick_index: i32,
     pub fee_growth_global_x: FeeGrowth,
     pub fee_growth_global_y: FeeGrowth,
+    pub seconds_per_liquidity_global: SecondsPerLiquidity,
     pub fee_protocol_token_x: TokenAmount,
     pub fee_protocol_token_y: TokenAmount,
     pub start_timestamp: u64,
@@ -45,6 +47,7 @@ impl Default for Pool {
             current_tick_index: i32::default(),
             fee_growth_global_x: FeeGrowth::default(),
             fee_growth_global_y: FeeGrowth::default(),
+            seconds_per_liquidity_global: SecondsPerLiquidity::default(),
             fee_protocol_token_x: TokenAmount::default(),
             fee_protocol_token_y: TokenAmount::default(),
             start_timestamp: u64::default(),
@@ -147,6 +150,40 @@ impl Pool {
         }
     }
 
+    // timestamps are block times in milliseconds, the accumulator counts whole seconds
+    pub fn get_seconds_per_liquidity_global(
+        &self,
+        current_timestamp: u64,
+    ) -> TrackableResult<SecondsPerLiquidity> {
+        let current_seconds = current_timestamp / MILLISECONDS_PER_SECOND;
+        let last_seconds = self.last_timestamp / MILLISECONDS_PER_SECOND;
+
+        if self.liquidity.is_zero() || current_seconds <= last_seconds {
+            return Ok(self.seconds_per_liquidity_global);
+        }
+
+        let seconds_per_liquidity =
+            ok_or_mark_trace!(SecondsPerLiquidity::calculate_seconds_per_liquidity_global(
+                self.liquidity,
+                current_seconds,
+                last_seconds,
+            ))?;
+
+        Ok(self
+            .seconds_per_liquidity_global
+            .unchecked_add(seconds_per_liquidity))
+    }
+
+    pub fn update_seconds_per_liquidity_global(
+        &mut self,
+        current_timestamp: u64,
+    ) -> TrackableResult<()> {
+        self.seconds_per_liquidity_global =
+            ok_or_mark_trace!(self.get_seconds_per_liquidity_global(current_timestamp))?;
+        self.last_timestamp = current_timestamp;
+        Ok(())
+    }
+
     pub fn withdraw_protocol_fee(&mut self, _pool_key: PoolKey) -> (TokenAmount, TokenAmount) {
         let fee_protocol_token_x = self.fee_protocol_token_x;
         let fee_protocol_token_y = self.fee_protocol_token_y;
@@ -605,4 +642,75 @@ mod tests {
             assert_eq!(pool.liquidity, Liquidity::from_integer(5),)
         }
     }
+
+    #[test]
+    fn test_update_seconds_per_liquidity_global() {
+        // zero liquidity only moves the timestamp
+        {
+            let mut pool = Pool {
+                last_timestamp: 10_000,
+                ..Default::default()
+            };
+
+            pool.update_seconds_per_liquidity_global(20_000).unwrap();
+
+            assert_eq!(
+                pool.seconds_per_liquidity_global,
+                SecondsPerLiquidity::new(U128::from(0))
+            );
+            assert_eq!(pool.last_timestamp, 20_000);
+        }
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::from_integer(2),
+                seconds_per_liquidity_global: SecondsPerLiquidity::from_integer(1),
+                last_timestamp: 10_000,
+                ..Default::default()
+            };
+
+            assert_eq!(
+                pool.get_seconds_per_liquidity_global(30_000).unwrap(),
+                SecondsPerLiquidity::from_integer(11)
+            );
+            assert_eq!(pool.last_timestamp, 10_000);
+
+            pool.update_seconds_per_liquidity_global(30_000).unwrap();
+
+            assert_eq!(
+                pool.seconds_per_liquidity_global,
+                SecondsPerLiquidity::from_integer(11)
+            );
+            assert_eq!(pool.last_timestamp, 30_000);
+
+            // same timestamp
+            pool.update_seconds_per_liquidity_global(30_000).unwrap();
+            assert_eq!(
+                pool.seconds_per_liquidity_global,
+                SecondsPerLiquidity::from_integer(11)
+            );
+
+            // less than a second does not accumulate
+            pool.update_seconds_per_liquidity_global(30_999).unwrap();
+            assert_eq!(
+                pool.seconds_per_liquidity_global,
+                SecondsPerLiquidity::from_integer(11)
+            );
+        }
+        // a long time without updates does not overflow
+        {
+            let mut pool = Pool {
+                liquidity: Liquidity::from_integer(1),
+                ..Default::default()
+            };
+            let ten_days = 10 * 24 * 60 * 60;
+
+            pool.update_seconds_per_liquidity_global(ten_days * MILLISECONDS_PER_SECOND)
+                .unwrap();
+
+            assert_eq!(
+                pool.seconds_per_liquidity_global,
+                SecondsPerLiquidity::from_integer(ten_days)
+            );
+        }
+    }
 }
@@ -12,6 +12,7 @@ use odra::OdraType;
 use traceable_result::*;
 #[derive(OdraType, Debug, Default, Copy, PartialEq)]
 pub struct Position {
+    pub id: u64,
     pub pool_key: PoolKey,
     pub liquidity: Liquidity,
     pub lower_tick_index: i32,
@@ -35,7 +36,7 @@ impl Position {
         current_timestamp: u64,
         tick_spacing: u32,
     ) -> TrackableResult<(TokenAmount, TokenAmount)> {
-        pool.last_timestamp = current_timestamp;
+        ok_or_mark_trace!(pool.update_seconds_per_liquidity_global(current_timestamp))?;
 
         // calculate dynamically limit allows easy modification
         let max_liquidity_per_tick = calculate_max_liquidity_per_tick(tick_spacing);
@@ -168,6 +169,7 @@ impl Position {
 
         // init position
         let mut position = Position {
+            id: 0,
             pool_key,
             liquidity: Liquidity::new(U256::from(0)),
             lower_tick_index: lower_tick.index,
@@ -192,6 +194,53 @@ impl Position {
         Ok((position, required_x, required_y))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn change_liquidity(
+        &mut self,
+        pool: &mut Pool,
+        lower_tick: &mut Tick,
+        upper_tick: &mut Tick,
+        current_timestamp: u64,
+        liquidity_delta: Liquidity,
+        add: bool,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        if liquidity_delta.is_zero() {
+            return Err(InvariantError::ZeroLiquidity);
+        }
+
+        // decreasing whole liquidity would leave an empty position, remove it instead
+        if !add && liquidity_delta >= self.liquidity {
+            return Err(InvariantError::InsufficientLiquidity);
+        }
+
+        if pool.sqrt_price < slippage_limit_lower || pool.sqrt_price > slippage_limit_upper {
+            return Err(InvariantError::PriceLimitReached);
+        }
+
+        let (mut amount_x, mut amount_y) = unwrap!(self.modify(
+            pool,
+            upper_tick,
+            lower_tick,
+            liquidity_delta,
+            add,
+            current_timestamp,
+            self.pool_key.fee_tier.tick_spacing
+        ));
+
+        // withdrawn liquidity is paid out together with accumulated fees
+        if !add {
+            amount_x += self.tokens_owed_x;
+            amount_y += self.tokens_owed_y;
+
+            self.tokens_owed_x = TokenAmount::new(U256::from(0));
+            self.tokens_owed_y = TokenAmount::new(U256::from(0));
+        }
+
+        Ok((amount_x, amount_y))
+    }
+
     pub fn remove(
         &mut self,
         pool: &mut Pool,
@@ -471,4 +520,71 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_change_liquidity() {
+        let mut pool = Pool {
+            sqrt_price: SqrtPrice::from_integer(1),
+            ..Default::default()
+        };
+        let mut lower_tick = Tick {
+            index: -10,
+            liquidity_gross: Liquidity::from_integer(1),
+            ..Default::default()
+        };
+        let mut upper_tick = Tick {
+            index: 10,
+            liquidity_gross: Liquidity::from_integer(1),
+            ..Default::default()
+        };
+        let mut position = Position {
+            liquidity: Liquidity::from_integer(1),
+            lower_tick_index: -10,
+            upper_tick_index: 10,
+            ..Default::default()
+        };
+        // zero liquidity delta
+        {
+            let result = position.change_liquidity(
+                &mut pool,
+                &mut lower_tick,
+                &mut upper_tick,
+                0,
+                Liquidity::new(U256::from(0)),
+                true,
+                SqrtPrice::new(U128::from(0)),
+                SqrtPrice::max_instance(),
+            );
+            assert_eq!(result, Err(InvariantError::ZeroLiquidity));
+        }
+        // decrease whole liquidity
+        {
+            let result = position.change_liquidity(
+                &mut pool,
+                &mut lower_tick,
+                &mut upper_tick,
+                0,
+                Liquidity::from_integer(1),
+                false,
+                SqrtPrice::new(U128::from(0)),
+                SqrtPrice::max_instance(),
+            );
+            assert_eq!(result, Err(InvariantError::InsufficientLiquidity));
+        }
+        // price out of slippage limits
+        {
+            let result = position.change_liquidity(
+                &mut pool,
+                &mut lower_tick,
+                &mut upper_tick,
+                0,
+                Liquidity::from_integer(1),
+                true,
+                SqrtPrice::from_integer(2),
+                SqrtPrice::max_instance(),
+            );
+            assert_eq!(result, Err(InvariantError::PriceLimitReached));
+        }
+        assert_eq!({ position.liquidity }, Liquidity::from_integer(1));
+    }
 }
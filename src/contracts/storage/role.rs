@@ -0,0 +1,9 @@
+use odra::OdraType;
+
+#[derive(OdraType, Debug, PartialEq, Eq, Copy)]
+pub enum Role {
+    FeeTierManager,
+    ProtocolFeeManager,
+    FeeReceiverManager,
+    Pauser,
+}
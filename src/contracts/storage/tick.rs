@@ -1,6 +1,7 @@
 use crate::contracts::Pool;
 use crate::math::fee_growth::FeeGrowth;
 use crate::math::liquidity::Liquidity;
+use crate::math::seconds_per_liquidity::SecondsPerLiquidity;
 use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
 use decimal::*;
 use odra::types::{U128, U256};
@@ -17,6 +18,7 @@ pub struct Tick {
     pub fee_growth_outside_x: FeeGrowth,
     pub fee_growth_outside_y: FeeGrowth,
     pub seconds_outside: u64,
+    pub seconds_per_liquidity_outside: SecondsPerLiquidity,
 }
 
 impl Default for Tick {
@@ -30,18 +32,19 @@ impl Default for Tick {
             fee_growth_outside_x: FeeGrowth::new(U128::from(0)),
             fee_growth_outside_y: FeeGrowth::new(U128::from(0)),
             seconds_outside: 0u64,
+            seconds_per_liquidity_outside: SecondsPerLiquidity::new(U128::from(0)),
         }
     }
 }
 
 impl Tick {
-    pub fn create(index: i32, pool: &Pool, current_timestamp: u64) -> Self {
+    pub fn create(index: i32, pool: &Pool, current_timestamp: u64) -> TrackableResult<Self> {
         let below_current_tick = index <= pool.current_tick_index;
 
-        Self {
+        Ok(Self {
             index,
             sign: true,
-            sqrt_price: calculate_sqrt_price(index).unwrap(),
+            sqrt_price: ok_or_mark_trace!(calculate_sqrt_price(index))?,
             fee_growth_outside_x: match below_current_tick {
                 true => pool.fee_growth_global_x,
                 false => FeeGrowth::new(U128::from(0)),
@@ -54,9 +57,15 @@ impl Tick {
                 true => current_timestamp - pool.start_timestamp,
                 false => 0,
             },
+            seconds_per_liquidity_outside: match below_current_tick {
+                true => {
+                    ok_or_mark_trace!(pool.get_seconds_per_liquidity_global(current_timestamp))?
+                }
+                false => SecondsPerLiquidity::new(U128::from(0)),
+            },
 
             ..Self::default()
-        }
+        })
     }
 
     pub fn cross(&mut self, pool: &mut Pool, current_timestamp: u64) -> TrackableResult<()> {
@@ -72,7 +81,11 @@ impl Tick {
             .ok_or_else(|| err!("current_timestamp - pool.start_timestamp underflow"))?;
         self.seconds_outside = seconds_passed.wrapping_sub(self.seconds_outside);
 
-        pool.last_timestamp = current_timestamp;
+        // accumulate with the liquidity from before the cross
+        ok_or_mark_trace!(pool.update_seconds_per_liquidity_global(current_timestamp))?;
+        self.seconds_per_liquidity_outside = pool
+            .seconds_per_liquidity_global
+            .unchecked_sub(self.seconds_per_liquidity_outside);
 
         // When going to higher tick net_liquidity should be added and for going lower subtracted
         if (pool.current_tick_index >= self.index) ^ self.sign {
@@ -288,4 +301,35 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    #[test]
+    fn test_cross() {
+        let mut pool = Pool {
+            liquidity: Liquidity::from_integer(1),
+            seconds_per_liquidity_global: SecondsPerLiquidity::from_integer(5),
+            current_tick_index: 0,
+            ..Default::default()
+        };
+        let mut tick = Tick {
+            index: 0,
+            sign: true,
+            liquidity_change: Liquidity::from_integer(1),
+            seconds_per_liquidity_outside: SecondsPerLiquidity::from_integer(2),
+            ..Default::default()
+        };
+
+        tick.cross(&mut pool, 10_000).unwrap();
+
+        assert_eq!(
+            pool.seconds_per_liquidity_global,
+            SecondsPerLiquidity::from_integer(15)
+        );
+        assert_eq!(
+            tick.seconds_per_liquidity_outside,
+            SecondsPerLiquidity::from_integer(13)
+        );
+        assert_eq!(pool.last_timestamp, 10_000);
+        assert_eq!(tick.seconds_outside, 10_000);
+        assert_eq!(pool.liquidity, Liquidity::from_integer(0));
+    }
 }
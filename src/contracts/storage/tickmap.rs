@@ -2,7 +2,8 @@ use super::PoolKey;
 use crate::contracts::InvariantError;
 use crate::math::sqrt_price::calculate_sqrt_price;
 use crate::math::sqrt_price::SqrtPrice;
-use crate::math::{CHUNK_SIZE, MAX_TICK, TICK_SEARCH_RANGE};
+use crate::math::{CHUNK_SIZE, MAX_TICK, TICKMAP_CHUNK_LIMIT, TICK_SEARCH_RANGE};
+use odra::prelude::vec::Vec;
 use odra::Mapping;
 
 #[odra::module]
@@ -209,6 +210,63 @@ impl Tickmap {
         }
     }
 
+    // Collects initialized ticks within <lower_tick, upper_tick> walking whole chunks,
+    // ordered in direction of trade (descending for x_to_y)
+    pub fn get_initialized_in_range(
+        &self,
+        lower_tick: i32,
+        upper_tick: i32,
+        tick_spacing: u32,
+        pool_key: PoolKey,
+        x_to_y: bool,
+    ) -> (Vec<i32>, Option<i32>) {
+        let (lower_chunk, lower_bit) = tick_to_position(lower_tick, tick_spacing);
+        let (upper_chunk, upper_bit) = tick_to_position(upper_tick, tick_spacing);
+        let offset = MAX_TICK / tick_spacing as i32;
+        let to_tick =
+            |chunk: u16, bit: i32| (chunk as i32 * CHUNK_SIZE + bit - offset) * tick_spacing as i32;
+        let mut ticks = Vec::new();
+
+        // at most `TICKMAP_CHUNK_LIMIT` chunks are read, starting from the side the trade begins at
+        let chunk_count = upper_chunk - lower_chunk + 1;
+        let read_count = chunk_count.min(TICKMAP_CHUNK_LIMIT);
+        let (first_chunk, last_chunk) = if x_to_y {
+            (upper_chunk + 1 - read_count, upper_chunk)
+        } else {
+            (lower_chunk, lower_chunk + read_count - 1)
+        };
+
+        for chunk in first_chunk..=last_chunk {
+            let mut value = self.bitmap.get(&(chunk, pool_key)).unwrap_or(0);
+            if chunk == upper_chunk && upper_bit < CHUNK_SIZE as u8 - 1 {
+                value &= (1u64 << (upper_bit + 1)) - 1;
+            }
+            if chunk == lower_chunk {
+                value &= !((1u64 << lower_bit) - 1);
+            }
+
+            while value != 0 {
+                ticks.push(to_tick(chunk, value.trailing_zeros() as i32));
+                value &= value - 1;
+            }
+        }
+
+        if x_to_y {
+            ticks.reverse();
+        }
+
+        // bound of the remaining range, passed as `upper_tick` if `x_to_y` and as `lower_tick` otherwise
+        let next_tick = if read_count == chunk_count {
+            None
+        } else if x_to_y {
+            Some(to_tick(first_chunk - 1, CHUNK_SIZE - 1))
+        } else {
+            Some(to_tick(last_chunk + 1, 0))
+        };
+
+        (ticks, next_tick)
+    }
+
     pub fn get(&self, tick: i32, tick_spacing: u32, pool_key: PoolKey) -> bool {
         let (chunk, bit) = tick_to_position(tick, tick_spacing);
         let returned_chunk = self.bitmap.get(&(chunk, pool_key)).unwrap_or(0);
@@ -237,6 +295,7 @@ mod tests {
     use super::*;
     use crate::{contracts::FeeTier, math::percentage::Percentage};
     use decimal::*;
+    use odra::prelude::vec;
     use odra::types::{casper_types::ContractPackageHash, Address, U128};
 
     #[test]
@@ -354,6 +413,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_initialized_in_range() {
+        let token_0: Address = Address::Contract(ContractPackageHash::from([0x01; 32]));
+        let token_1: Address = Address::Contract(ContractPackageHash::from([0x02; 32]));
+        let fee_tier: FeeTier = FeeTier {
+            fee: Percentage::new(U128::from(1)),
+            tick_spacing: 10,
+        };
+        let pool_key: PoolKey = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+
+        let tickmap = &mut TickmapDeployer::default();
+        for index in [-1000, -20, 0, 630, 640, 5000] {
+            tickmap.flip(true, index, 10, pool_key);
+        }
+        // across chunks
+        {
+            let result = tickmap.get_initialized_in_range(-1000, 1000, 10, pool_key, false);
+            assert_eq!(result, (vec![-1000, -20, 0, 630, 640], None));
+
+            let result = tickmap.get_initialized_in_range(-1000, 1000, 10, pool_key, true);
+            assert_eq!(result, (vec![640, 630, 0, -20, -1000], None));
+        }
+        // bounds are inclusive
+        {
+            let result = tickmap.get_initialized_in_range(-20, 630, 10, pool_key, false);
+            assert_eq!(result, (vec![-20, 0, 630], None));
+
+            let result = tickmap.get_initialized_in_range(-10, 620, 10, pool_key, false);
+            assert_eq!(result, (vec![0], None));
+        }
+        // within a single chunk
+        {
+            let result = tickmap.get_initialized_in_range(4990, 5010, 10, pool_key, true);
+            assert_eq!(result, (vec![5000], None));
+
+            let (ticks, next_tick) = tickmap.get_initialized_in_range(10, 20, 10, pool_key, true);
+            assert!(ticks.is_empty());
+            assert_eq!(next_tick, None);
+        }
+        // whole domain is read in pages of `TICKMAP_CHUNK_LIMIT` chunks
+        {
+            let max_tick = MAX_TICK - MAX_TICK % 10;
+            let (ticks, next_tick) =
+                tickmap.get_initialized_in_range(-max_tick, max_tick, 10, pool_key, false);
+            assert!(ticks.is_empty());
+            assert_eq!(
+                next_tick,
+                Some((TICKMAP_CHUNK_LIMIT as i32 * CHUNK_SIZE - MAX_TICK / 10) * 10)
+            );
+
+            let mut ticks = vec![];
+            let mut lower_tick = -max_tick;
+            loop {
+                let (page, next_tick) =
+                    tickmap.get_initialized_in_range(lower_tick, max_tick, 10, pool_key, false);
+                ticks.extend(page);
+                match next_tick {
+                    Some(next_tick) => lower_tick = next_tick,
+                    None => break,
+                }
+            }
+            assert_eq!(ticks, vec![-1000, -20, 0, 630, 640, 5000]);
+
+            let mut ticks = vec![];
+            let mut upper_tick = max_tick;
+            loop {
+                let (page, next_tick) =
+                    tickmap.get_initialized_in_range(-max_tick, upper_tick, 10, pool_key, true);
+                ticks.extend(page);
+                match next_tick {
+                    Some(next_tick) => upper_tick = next_tick,
+                    None => break,
+                }
+            }
+            assert_eq!(ticks, vec![5000, 640, 630, 0, -20, -1000]);
+        }
+    }
+
     #[test]
     fn test_next_initialized_simple() {
         let token_0: Address = Address::Contract(ContractPackageHash::from([0x01; 32]));
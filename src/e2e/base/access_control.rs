@@ -0,0 +1,193 @@
+use crate::contracts::{
+    AdminChangedEvent, AdminProposedEvent, FeeTier, InvariantError, Role, RoleGrantedEvent,
+    RoleRevokedEvent,
+};
+use crate::math::percentage::Percentage;
+use crate::InvariantDeployer;
+use decimal::*;
+use odra::assert_events;
+use odra::test_env;
+use odra::types::U128;
+
+#[test]
+fn test_transfer_admin() {
+    let deployer = test_env::get_account(0);
+    let new_admin = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    assert_eq!(invariant.get_admin(), deployer);
+    assert_eq!(invariant.get_pending_admin(), None);
+
+    invariant.propose_admin(new_admin).unwrap();
+    assert_eq!(invariant.get_admin(), deployer);
+    assert_eq!(invariant.get_pending_admin(), Some(new_admin));
+    assert_events!(
+        invariant,
+        AdminProposedEvent {
+            timestamp: 0,
+            admin: deployer,
+            pending_admin: new_admin,
+        }
+    );
+
+    test_env::set_caller(new_admin);
+    invariant.accept_admin().unwrap();
+    assert_eq!(invariant.get_admin(), new_admin);
+    assert_eq!(invariant.get_pending_admin(), None);
+    assert_events!(
+        invariant,
+        AdminChangedEvent {
+            timestamp: 0,
+            previous_admin: deployer,
+            admin: new_admin,
+        }
+    );
+
+    let new_fee = Percentage::new(U128::from(1)).get();
+    invariant.change_protocol_fee(new_fee).unwrap();
+    assert_eq!(invariant.get_protocol_fee().get(), new_fee);
+}
+
+#[test]
+#[should_panic]
+fn test_old_admin_loses_rights_after_transfer() {
+    let deployer = test_env::get_account(0);
+    let new_admin = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    invariant.propose_admin(new_admin).unwrap();
+    test_env::set_caller(new_admin);
+    invariant.accept_admin().unwrap();
+
+    test_env::set_caller(deployer);
+    let result = invariant.change_protocol_fee(Percentage::new(U128::from(1)).get());
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
+
+#[test]
+#[should_panic]
+fn test_propose_admin_not_admin() {
+    let deployer = test_env::get_account(0);
+    let not_admin = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    test_env::set_caller(not_admin);
+    let result = invariant.propose_admin(not_admin);
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
+
+#[test]
+#[should_panic]
+fn test_accept_admin_not_pending_admin() {
+    let deployer = test_env::get_account(0);
+    let new_admin = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    invariant.propose_admin(new_admin).unwrap();
+
+    test_env::set_caller(test_env::get_account(2));
+    let result = invariant.accept_admin();
+    assert_eq!(result, Err(InvariantError::NotPendingAdmin));
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let deployer = test_env::get_account(0);
+    let fee_tier_manager = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    assert!(!invariant.has_role(Role::FeeTierManager, fee_tier_manager));
+
+    invariant
+        .grant_role(Role::FeeTierManager, fee_tier_manager)
+        .unwrap();
+    assert!(invariant.has_role(Role::FeeTierManager, fee_tier_manager));
+    assert!(!invariant.has_role(Role::ProtocolFeeManager, fee_tier_manager));
+    assert_events!(
+        invariant,
+        RoleGrantedEvent {
+            timestamp: 0,
+            role: Role::FeeTierManager,
+            account: fee_tier_manager,
+            sender: deployer,
+        }
+    );
+
+    let fee_tier = FeeTier::new(Percentage::new(U128::from(10)), 1).unwrap();
+    test_env::set_caller(fee_tier_manager);
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+    assert!(invariant.fee_tier_exist(fee_tier.fee.get(), fee_tier.tick_spacing));
+
+    test_env::set_caller(deployer);
+    invariant
+        .revoke_role(Role::FeeTierManager, fee_tier_manager)
+        .unwrap();
+    assert!(!invariant.has_role(Role::FeeTierManager, fee_tier_manager));
+    assert_events!(
+        invariant,
+        RoleRevokedEvent {
+            timestamp: 0,
+            role: Role::FeeTierManager,
+            account: fee_tier_manager,
+            sender: deployer,
+        }
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_role_does_not_grant_other_powers() {
+    let deployer = test_env::get_account(0);
+    let fee_tier_manager = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    invariant
+        .grant_role(Role::FeeTierManager, fee_tier_manager)
+        .unwrap();
+
+    test_env::set_caller(fee_tier_manager);
+    let result = invariant.change_protocol_fee(Percentage::new(U128::from(1)).get());
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
+
+#[test]
+#[should_panic]
+fn test_revoked_role_loses_powers() {
+    let deployer = test_env::get_account(0);
+    let fee_tier_manager = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    invariant
+        .grant_role(Role::FeeTierManager, fee_tier_manager)
+        .unwrap();
+    invariant
+        .revoke_role(Role::FeeTierManager, fee_tier_manager)
+        .unwrap();
+
+    let fee_tier = FeeTier::new(Percentage::new(U128::from(10)), 1).unwrap();
+    test_env::set_caller(fee_tier_manager);
+    let result = invariant.add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing);
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
+
+#[test]
+#[should_panic]
+fn test_grant_role_not_admin() {
+    let deployer = test_env::get_account(0);
+    let not_admin = test_env::get_account(1);
+    test_env::set_caller(deployer);
+    let mut invariant = InvariantDeployer::init(U128::from(0));
+
+    test_env::set_caller(not_admin);
+    let result = invariant.grant_role(Role::Pauser, not_admin);
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
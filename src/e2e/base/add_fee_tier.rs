@@ -1,8 +1,10 @@
 use crate::contracts::errors::InvariantError;
+use crate::contracts::AddFeeTierEvent;
 use crate::math::percentage::Percentage;
 use crate::FeeTier;
 use crate::InvariantDeployer;
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::U128;
 
@@ -38,6 +40,25 @@ fn test_add_multiple_fee_tiers() {
     assert_eq!(fee_tiers[0], first_fee_tier);
     assert_eq!(fee_tiers[1], second_fee_tier);
     assert_eq!(fee_tiers[2], third_fee_tier);
+
+    assert_events!(
+        invariant,
+        AddFeeTierEvent {
+            timestamp: 0,
+            address: deployer,
+            fee_tier: first_fee_tier,
+        },
+        AddFeeTierEvent {
+            timestamp: 0,
+            address: deployer,
+            fee_tier: second_fee_tier,
+        },
+        AddFeeTierEvent {
+            timestamp: 0,
+            address: deployer,
+            fee_tier: third_fee_tier,
+        }
+    );
 }
 
 #[test]
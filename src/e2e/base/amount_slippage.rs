@@ -0,0 +1,178 @@
+use crate::contracts::PoolKey;
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::calculate_sqrt_price;
+use crate::math::MIN_SQRT_PRICE;
+use crate::InvariantRef;
+use decimal::Decimal;
+use odra::test_env;
+use odra::types::{U128, U256};
+
+fn swap_with_slippage(
+    invariant: &mut InvariantRef,
+    pool_key: PoolKey,
+    amount: U256,
+    by_amount_in: bool,
+    expected_amount: U256,
+) {
+    invariant
+        .swap_with_slippage(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            amount,
+            by_amount_in,
+            U128::from(MIN_SQRT_PRICE),
+            expected_amount,
+            Percentage::new(U128::from(0)).get(),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_swap_with_slippage_by_amount_in() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    swap_with_slippage(
+        &mut invariant,
+        pool_key,
+        U256::from(1000),
+        true,
+        U256::from(993),
+    );
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert!(pool.sqrt_price < calculate_sqrt_price(0).unwrap());
+}
+
+#[test]
+#[should_panic]
+fn test_swap_with_slippage_under_minimum_amount_out() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    swap_with_slippage(
+        &mut invariant,
+        pool_key,
+        U256::from(1000),
+        true,
+        U256::from(1000),
+    );
+}
+
+#[test]
+fn test_swap_with_slippage_by_amount_out() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let quote = invariant
+        .quote(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(500),
+            false,
+            U128::from(MIN_SQRT_PRICE),
+        )
+        .unwrap();
+
+    swap_with_slippage(
+        &mut invariant,
+        pool_key,
+        U256::from(500),
+        false,
+        quote.amount_in.get(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_swap_with_slippage_over_maximum_amount_in() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let quote = invariant
+        .quote(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(500),
+            false,
+            U128::from(MIN_SQRT_PRICE),
+        )
+        .unwrap();
+
+    swap_with_slippage(
+        &mut invariant,
+        pool_key,
+        U256::from(500),
+        false,
+        quote.amount_in.get() - 1,
+    );
+}
+
+#[test]
+fn test_remove_position_with_min_amounts() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    let first = init_basic_position(&mut invariant, pool_key);
+    let second = init_basic_position(&mut invariant, pool_key);
+
+    let (amount_x, amount_y) = invariant
+        .remove_position_with_min_amounts(first.id, U256::from(0), U256::from(0))
+        .unwrap();
+    assert!(amount_x.get() > U256::from(0));
+    assert!(amount_y.get() > U256::from(0));
+
+    let result = invariant
+        .remove_position_with_min_amounts(second.id, amount_x.get(), amount_y.get())
+        .unwrap();
+    assert_eq!(result, (amount_x, amount_y));
+}
+
+#[test]
+#[should_panic]
+fn test_remove_position_below_min_amounts() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    let first = init_basic_position(&mut invariant, pool_key);
+    let second = init_basic_position(&mut invariant, pool_key);
+
+    let (amount_x, amount_y) = invariant
+        .remove_position_with_min_amounts(first.id, U256::from(0), U256::from(0))
+        .unwrap();
+
+    invariant
+        .remove_position_with_min_amounts(second.id, amount_x.get() + 1, amount_y.get())
+        .unwrap();
+}
@@ -1,9 +1,11 @@
 use crate::contracts::errors::InvariantError;
+use crate::contracts::ChangeFeeReceiverEvent;
 use crate::math::percentage::Percentage;
 use crate::math::sqrt_price::calculate_sqrt_price;
 use crate::InvariantDeployer;
 use crate::{FeeTier, PoolKey};
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::casper_types::ContractPackageHash;
 use odra::types::Address;
@@ -40,7 +42,7 @@ fn test_change_fee_reciever() {
         .unwrap();
 
     let new_receiver = test_env::get_account(1);
-    let _pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
+    let pool_key = PoolKey::new(token_0, token_1, fee_tier).unwrap();
 
     invariant
         .change_fee_receiver(
@@ -56,6 +58,16 @@ fn test_change_fee_reciever() {
         .get_pool(token_0, token_1, fee_tier.fee.get(), fee_tier.tick_spacing)
         .unwrap();
     assert_eq!(pool.fee_receiver, new_receiver);
+
+    assert_events!(
+        invariant,
+        ChangeFeeReceiverEvent {
+            timestamp: 0,
+            address: deployer,
+            pool: pool_key,
+            fee_receiver: new_receiver,
+        }
+    );
 }
 
 #[test]
@@ -0,0 +1,282 @@
+use crate::contracts::PoolKey;
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::{contracts::FeeTier, math::percentage::Percentage};
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_increase_and_decrease_liquidity() {
+    let position_owner = test_env::get_account(0);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let lower_tick = -20;
+    let upper_tick = 10;
+    let liquidity_delta = Liquidity::from_integer(1000000);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            lower_tick,
+            upper_tick,
+            liquidity_delta.get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    let deposited_x = token_x.balance_of(invariant.address());
+    let deposited_y = token_y.balance_of(invariant.address());
+
+    // Increase liquidity
+    {
+        let position = invariant
+            .increase_liquidity(
+                0,
+                liquidity_delta.get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            )
+            .unwrap();
+
+        let pool = invariant
+            .get_pool(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+        let lower_tick = invariant
+            .get_tick(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+            )
+            .unwrap();
+        let upper_tick = invariant
+            .get_tick(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                upper_tick,
+            )
+            .unwrap();
+
+        assert_eq!(position.liquidity, liquidity_delta + liquidity_delta);
+        assert_eq!(invariant.get_position(0).unwrap(), position);
+        assert_eq!(invariant.get_all_positions(position_owner).len(), 1);
+        assert_eq!(pool.liquidity, liquidity_delta + liquidity_delta);
+        assert_eq!(
+            lower_tick.liquidity_gross,
+            liquidity_delta + liquidity_delta
+        );
+        assert_eq!(
+            upper_tick.liquidity_gross,
+            liquidity_delta + liquidity_delta
+        );
+
+        assert_eq!(
+            token_x.balance_of(invariant.address()),
+            deposited_x + deposited_x
+        );
+        assert_eq!(
+            token_y.balance_of(invariant.address()),
+            deposited_y + deposited_y
+        );
+    }
+    // Decrease liquidity
+    {
+        let user_x_before = token_x.balance_of(&position_owner);
+        let user_y_before = token_y.balance_of(&position_owner);
+
+        let (amount_x, amount_y) = invariant
+            .decrease_liquidity(
+                0,
+                liquidity_delta.get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            )
+            .unwrap();
+
+        let pool = invariant
+            .get_pool(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+        let position = invariant.get_position(0).unwrap();
+
+        assert_eq!(position.liquidity, liquidity_delta);
+        assert_eq!(pool.liquidity, liquidity_delta);
+        assert!(amount_x.get() <= deposited_x);
+        assert!(amount_y.get() <= deposited_y);
+
+        assert_eq!(
+            token_x.balance_of(&position_owner),
+            user_x_before + amount_x.get()
+        );
+        assert_eq!(
+            token_y.balance_of(&position_owner),
+            user_y_before + amount_y.get()
+        );
+        assert_eq!(
+            token_x.balance_of(invariant.address()),
+            deposited_x + deposited_x - amount_x.get()
+        );
+        assert_eq!(
+            token_y.balance_of(invariant.address()),
+            deposited_y + deposited_y - amount_y.get()
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_decrease_whole_liquidity() {
+    let position_owner = test_env::get_account(0);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let liquidity_delta = Liquidity::from_integer(1000000);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            liquidity_delta.get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    invariant
+        .decrease_liquidity(
+            0,
+            liquidity_delta.get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_increase_liquidity_not_position_owner() {
+    let position_owner = test_env::get_account(0);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let liquidity_delta = Liquidity::from_integer(1000000);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            liquidity_delta.get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    test_env::set_caller(test_env::get_account(1));
+    invariant
+        .increase_liquidity(
+            0,
+            liquidity_delta.get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+}
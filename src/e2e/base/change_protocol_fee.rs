@@ -1,7 +1,9 @@
 use crate::contracts::errors::InvariantError;
+use crate::contracts::ChangeProtocolFeeEvent;
 use crate::math::percentage::Percentage;
 use crate::InvariantDeployer;
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::U128;
 
@@ -19,6 +21,15 @@ fn test_change_protocol_fee() {
 
     let protocol_fee = invariant.get_protocol_fee();
     assert_eq!(protocol_fee.get(), new_fee);
+
+    assert_events!(
+        invariant,
+        ChangeProtocolFeeEvent {
+            timestamp: 0,
+            address: deployer,
+            protocol_fee,
+        }
+    );
 }
 
 #[test]
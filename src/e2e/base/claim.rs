@@ -1,5 +1,5 @@
 use crate::contracts::InvariantError;
-use crate::contracts::PoolKey;
+use crate::contracts::{ClaimFeeEvent, PoolKey};
 use crate::e2e::snippets::init;
 use crate::math::fee_growth::FeeGrowth;
 use crate::math::liquidity::Liquidity;
@@ -10,6 +10,7 @@ use crate::math::token_amount::TokenAmount;
 use crate::math::MIN_SQRT_PRICE;
 use crate::FeeTier;
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::{U128, U256};
 
@@ -190,7 +191,7 @@ fn test_claim() {
                 fee_tier.tick_spacing,
             )
             .unwrap();
-        let position = invariant.get_position(position_owner, 0).unwrap();
+        let position = invariant.get_position(0).unwrap();
         let user_amount_after_claim = token_x.balance_of(&position_owner);
         let dex_amount_after_claim = token_x.balance_of(invariant.address());
         let expected_tokens_claimed = U256::from(5);
@@ -205,6 +206,19 @@ fn test_claim() {
         );
         assert_eq!(position.fee_growth_inside_x, pool.fee_growth_global_x);
         assert_eq!(position.tokens_owed_x, TokenAmount::new(U256::from(0)));
+
+        assert_events!(
+            invariant,
+            ClaimFeeEvent {
+                timestamp: 0,
+                address: position_owner,
+                owner: position_owner,
+                position_id: 0,
+                pool: pool_key,
+                amount_x: TokenAmount::new(expected_tokens_claimed),
+                amount_y: TokenAmount::new(U256::from(0)),
+            }
+        );
     }
 }
 
@@ -373,6 +387,6 @@ fn test_claim_not_position_owner() {
         let unauthorized_user = test_env::get_account(1);
         test_env::set_caller(unauthorized_user);
         let result = invariant.claim_fee(0);
-        assert_eq!(result, Err(InvariantError::PositionNotFound));
+        assert_eq!(result, Err(InvariantError::NotPositionOwner));
     }
 }
@@ -1,10 +1,12 @@
 use crate::contracts::errors::InvariantError;
+use crate::contracts::{CreatePoolEvent, PoolKey};
 use crate::math::percentage::Percentage;
 use crate::math::sqrt_price::calculate_sqrt_price;
 use crate::math::sqrt_price::SqrtPrice;
 use crate::FeeTier;
 use crate::InvariantDeployer;
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::casper_types::ContractPackageHash;
 use odra::types::Address;
@@ -43,6 +45,17 @@ fn test_create_pool() {
     invariant
         .get_pool(token_0, token_1, fee_tier.fee.get(), fee_tier.tick_spacing)
         .unwrap();
+
+    assert_events!(
+        invariant,
+        CreatePoolEvent {
+            timestamp: 0,
+            address: deployer,
+            pool: PoolKey::new(token_0, token_1, fee_tier).unwrap(),
+            init_sqrt_price,
+            init_tick,
+        }
+    );
 }
 
 #[test]
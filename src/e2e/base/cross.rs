@@ -198,6 +198,7 @@ fn test_cross() {
         );
         assert_eq!(pool_after.current_tick_index, expected_tick);
         assert_ne!(pool_after.sqrt_price, pool_before.sqrt_price);
+        assert_eq!(result.ticks.len(), 1);
 
         let amount_x = token_x.balance_of(&caller);
         let amount_y = token_y.balance_of(&caller);
@@ -251,7 +252,9 @@ fn test_cross() {
                 timestamp: 0,
                 address: caller,
                 pool: pool_key,
-                indexes: result.ticks.iter().map(|tick| tick.index).collect(),
+                index: -10,
+                sign: false,
+                liquidity_change: position_liquidity,
             },
             SwapEvent {
                 timestamp: 0,
@@ -263,6 +266,9 @@ fn test_cross() {
                 start_sqrt_price: pool_before.sqrt_price,
                 target_sqrt_price: pool_after.sqrt_price,
                 x_to_y: true,
+                by_amount_in: true,
+                liquidity: pool_after.liquidity,
+                current_tick_index: expected_tick,
             }
         );
     }
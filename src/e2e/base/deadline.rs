@@ -0,0 +1,120 @@
+use crate::contracts::InvariantError;
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::liquidity::Liquidity;
+use crate::math::sqrt_price::SqrtPrice;
+use crate::math::MIN_SQRT_PRICE;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_deadline() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+
+    // deadline is inclusive
+    invariant
+        .create_position_with_deadline(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+            0,
+        )
+        .unwrap();
+
+    test_env::advance_block_time_by(10);
+
+    invariant
+        .swap_with_deadline(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+            100,
+        )
+        .unwrap();
+
+    invariant.remove_position_with_deadline(0, 10).unwrap();
+    assert_eq!(
+        invariant.get_position(0),
+        Err(InvariantError::PositionNotFound)
+    );
+}
+
+#[test]
+fn test_swap_at_deadline() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    // block time is in milliseconds, the deadline equal to it still passes
+    test_env::advance_block_time_by(10);
+
+    invariant
+        .swap_with_deadline(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+            10,
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_swap_after_deadline() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    test_env::advance_block_time_by(10);
+
+    let result = invariant.swap_with_deadline(
+        pool_key.token_x,
+        pool_key.token_y,
+        pool_key.fee_tier.fee.get(),
+        pool_key.fee_tier.tick_spacing,
+        true,
+        U256::from(1000),
+        true,
+        U128::from(MIN_SQRT_PRICE),
+        9,
+    );
+    assert_eq!(result, Err(InvariantError::DeadlineExceeded));
+}
+
+#[test]
+#[should_panic]
+fn test_remove_position_after_deadline() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    test_env::advance_block_time_by(10);
+
+    let result = invariant.remove_position_with_deadline(0, 9);
+    assert_eq!(result, Err(InvariantError::DeadlineExceeded));
+}
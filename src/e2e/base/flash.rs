@@ -0,0 +1,298 @@
+use crate::contracts::{FeeTier, FlashEvent, PoolKey};
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::token_amount::TokenAmount;
+use crate::{Erc20Ref, InvariantRef};
+use alloc::vec;
+use decimal::{Decimal, Factories};
+use odra::assert_events;
+use odra::prelude::vec::Vec;
+use odra::test_env;
+use odra::types::{Address, U128, U256};
+use odra::{contract_env, UnwrapOrRevert, Variable};
+
+#[odra::module]
+pub struct FlashBorrower {
+    invariant: Variable<Address>,
+    pool_key: Variable<PoolKey>,
+}
+
+#[odra::module]
+impl FlashBorrower {
+    #[odra(init)]
+    pub fn init(&mut self, invariant: Address, pool_key: PoolKey) {
+        self.invariant.set(invariant);
+        self.pool_key.set(pool_key);
+    }
+
+    pub fn invariant_flash_callback(
+        &mut self,
+        amount_x: U256,
+        amount_y: U256,
+        fee_x: U256,
+        fee_y: U256,
+        data: Vec<u8>,
+    ) {
+        let invariant = self.invariant.get().unwrap_or_revert();
+        let pool_key = self.pool_key.get().unwrap_or_revert();
+
+        match data.first() {
+            // the borrower keeps the loan
+            Some(1) => return,
+            // the borrower tries to borrow the same amounts again before repaying
+            Some(2) => {
+                InvariantRef::at(&invariant)
+                    .flash(
+                        pool_key.token_x,
+                        pool_key.token_y,
+                        pool_key.fee_tier.fee.get(),
+                        pool_key.fee_tier.tick_spacing,
+                        amount_x,
+                        amount_y,
+                        contract_env::self_address(),
+                        vec![],
+                    )
+                    .unwrap();
+            }
+            _ => {}
+        }
+
+        Erc20Ref::at(&pool_key.token_x).approve(&invariant, &(amount_x + fee_x));
+        Erc20Ref::at(&pool_key.token_y).approve(&invariant, &(amount_y + fee_y));
+    }
+}
+
+fn init_flash() -> (InvariantRef, Erc20Ref, Erc20Ref, PoolKey, Address) {
+    let (mut invariant, mut token_x, mut token_y, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let borrower = *FlashBorrowerDeployer::init(*invariant.address(), pool_key).address();
+
+    // borrower has to cover the fee from its own funds
+    token_x.transfer(&borrower, &U256::from(100));
+    token_y.transfer(&borrower, &U256::from(100));
+
+    (invariant, token_x, token_y, pool_key, borrower)
+}
+
+// a second pool of the same tokens raises the contract balance but not the reserves of the first one
+fn init_second_pool(invariant: &mut InvariantRef, pool_key: PoolKey) {
+    let fee_tier = FeeTier::new(Percentage::from_scale(1, 2), 10).unwrap();
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_flash() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key, borrower) = init_flash();
+
+    let invariant_balance_x = token_x.balance_of(invariant.address());
+    let invariant_balance_y = token_y.balance_of(invariant.address());
+
+    let (fee_x, fee_y) = invariant
+        .flash(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            U256::from(500),
+            U256::from(1000),
+            borrower,
+            vec![],
+        )
+        .unwrap();
+
+    assert_eq!(fee_x, TokenAmount::new(U256::from(3)));
+    assert_eq!(fee_y, TokenAmount::new(U256::from(6)));
+
+    assert_events!(
+        invariant,
+        FlashEvent {
+            timestamp: 0,
+            address: deployer,
+            receiver: borrower,
+            pool: pool_key,
+            amount_x: TokenAmount::new(U256::from(500)),
+            amount_y: TokenAmount::new(U256::from(1000)),
+            fee_x,
+            fee_y,
+        }
+    );
+
+    assert_eq!(token_x.balance_of(&borrower), U256::from(97));
+    assert_eq!(token_y.balance_of(&borrower), U256::from(94));
+    assert_eq!(
+        token_x.balance_of(invariant.address()),
+        invariant_balance_x + U256::from(3)
+    );
+    assert_eq!(
+        token_y.balance_of(invariant.address()),
+        invariant_balance_y + U256::from(6)
+    );
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+
+    // 1% protocol fee rounded up
+    assert_eq!(pool.fee_protocol_token_x, TokenAmount::new(U256::from(1)));
+    assert_eq!(pool.fee_protocol_token_y, TokenAmount::new(U256::from(1)));
+    assert!(!pool.fee_growth_global_x.is_zero());
+    assert!(!pool.fee_growth_global_y.is_zero());
+}
+
+#[test]
+#[should_panic]
+fn test_flash_not_repaid() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key, borrower) = init_flash();
+
+    invariant
+        .flash(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            U256::from(500),
+            U256::from(0),
+            borrower,
+            vec![1],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_flash_over_pool_reserves() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key, borrower) = init_flash();
+    init_second_pool(&mut invariant, pool_key);
+
+    invariant
+        .flash(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            U256::from(501),
+            U256::from(0),
+            borrower,
+            vec![],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_flash_nested_over_pool_reserves() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key, borrower) = init_flash();
+    init_second_pool(&mut invariant, pool_key);
+
+    // the lent amount is out of the reserves until repaid, so the nested loan can't take it again
+    invariant
+        .flash(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            U256::from(500),
+            U256::from(0),
+            borrower,
+            vec![2],
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_flash_without_active_liquidity() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key, borrower) = init_flash();
+
+    let position = invariant.get_all_positions(deployer).pop().unwrap();
+    invariant.remove_position(position.id).unwrap();
+
+    // position above the current tick holds only token x and is not active
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            10,
+            20,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    let (fee_x, _) = invariant
+        .flash(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            U256::from(100),
+            U256::from(0),
+            borrower,
+            vec![],
+        )
+        .unwrap();
+    assert_eq!(fee_x, TokenAmount::new(U256::from(1)));
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+
+    assert!(pool.liquidity.is_zero());
+    assert!(pool.fee_growth_global_x.is_zero());
+    assert_eq!(pool.fee_protocol_token_x, fee_x);
+}
@@ -0,0 +1,170 @@
+use crate::contracts::PoolKey;
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::token_amount::TokenAmount;
+use crate::math::MIN_SQRT_PRICE;
+use crate::{Erc20Ref, InvariantRef};
+use alloc::vec;
+use decimal::Decimal;
+use odra::prelude::vec::Vec;
+use odra::test_env;
+use odra::types::{Address, U128, U256};
+use odra::{UnwrapOrRevert, Variable};
+
+#[odra::module]
+pub struct SwapPayer {
+    invariant: Variable<Address>,
+    token_x: Variable<Address>,
+    token_y: Variable<Address>,
+}
+
+#[odra::module]
+impl SwapPayer {
+    #[odra(init)]
+    pub fn init(&mut self, invariant: Address, token_x: Address, token_y: Address) {
+        self.invariant.set(invariant);
+        self.token_x.set(token_x);
+        self.token_y.set(token_y);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap(
+        &mut self,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        sqrt_price_limit: U128,
+        data: Vec<u8>,
+    ) {
+        InvariantRef::at(&self.invariant.get().unwrap_or_revert())
+            .flash_swap(
+                self.token_x.get().unwrap_or_revert(),
+                self.token_y.get().unwrap_or_revert(),
+                fee,
+                tick_spacing,
+                x_to_y,
+                amount,
+                true,
+                sqrt_price_limit,
+                data,
+            )
+            .unwrap();
+    }
+
+    pub fn invariant_swap_callback(
+        &mut self,
+        x_to_y: bool,
+        amount_in: U256,
+        _amount_out: U256,
+        data: Vec<u8>,
+    ) {
+        let invariant = self.invariant.get().unwrap_or_revert();
+
+        match data.first() {
+            // the payer skips the payment
+            Some(1) => return,
+            // the swap has to be visible in the pool before the payment
+            Some(2) => {
+                let token_x = self.token_x.get().unwrap_or_revert();
+                let token_y = self.token_y.get().unwrap_or_revert();
+                let fee_tier = InvariantRef::at(&invariant).get_fee_tiers()[0];
+                let pool = InvariantRef::at(&invariant)
+                    .get_pool(token_x, token_y, fee_tier.fee.get(), fee_tier.tick_spacing)
+                    .unwrap();
+                assert_eq!(pool.current_tick_index, -20);
+            }
+            _ => {}
+        }
+
+        let token_in = if x_to_y {
+            self.token_x.get().unwrap_or_revert()
+        } else {
+            self.token_y.get().unwrap_or_revert()
+        };
+
+        Erc20Ref::at(&token_in).approve(&invariant, &amount_in);
+    }
+}
+
+fn init_flash_swap() -> (InvariantRef, Erc20Ref, Erc20Ref, PoolKey, Address) {
+    let (mut invariant, mut token_x, token_y, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let payer = *SwapPayerDeployer::init(*invariant.address(), pool_key.token_x, pool_key.token_y)
+        .address();
+
+    token_x.mint(&payer, &U256::from(1000));
+
+    (invariant, token_x, token_y, pool_key, payer)
+}
+
+#[test]
+fn test_flash_swap() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (invariant, token_x, token_y, pool_key, payer) = init_flash_swap();
+
+    SwapPayerRef::at(&payer).swap(
+        pool_key.fee_tier.fee.get(),
+        pool_key.fee_tier.tick_spacing,
+        true,
+        U256::from(1000),
+        U128::from(MIN_SQRT_PRICE),
+        vec![],
+    );
+
+    assert_eq!(token_x.balance_of(&payer), U256::from(0));
+    assert_eq!(token_y.balance_of(&payer), U256::from(993));
+    assert_eq!(token_x.balance_of(invariant.address()), U256::from(1500));
+    assert_eq!(token_y.balance_of(invariant.address()), U256::from(7));
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool.current_tick_index, -20);
+    assert_eq!(pool.fee_protocol_token_x, TokenAmount::new(U256::from(1)));
+}
+
+#[test]
+#[should_panic]
+fn test_flash_swap_not_paid() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (_, _, _, pool_key, payer) = init_flash_swap();
+
+    SwapPayerRef::at(&payer).swap(
+        pool_key.fee_tier.fee.get(),
+        pool_key.fee_tier.tick_spacing,
+        true,
+        U256::from(1000),
+        U128::from(MIN_SQRT_PRICE),
+        vec![1],
+    );
+}
+
+#[test]
+fn test_flash_swap_pool_updated_before_callback() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (invariant, token_x, _, pool_key, payer) = init_flash_swap();
+
+    SwapPayerRef::at(&payer).swap(
+        pool_key.fee_tier.fee.get(),
+        pool_key.fee_tier.tick_spacing,
+        true,
+        U256::from(1000),
+        U128::from(MIN_SQRT_PRICE),
+        vec![2],
+    );
+
+    assert_eq!(token_x.balance_of(&payer), U256::from(0));
+    assert_eq!(token_x.balance_of(invariant.address()), U256::from(1500));
+}
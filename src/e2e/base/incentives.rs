@@ -0,0 +1,189 @@
+use crate::contracts::{InvariantError, PoolKey};
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::token_amount::TokenAmount;
+use crate::math::MIN_SQRT_PRICE;
+use crate::{Erc20Deployer, Erc20Ref, InvariantRef};
+use alloc::string::String;
+use decimal::Decimal;
+use odra::test_env;
+use odra::types::{U128, U256};
+
+fn init_incentive() -> (InvariantRef, Erc20Ref, PoolKey) {
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let mut reward_token =
+        Erc20Deployer::init(String::from(""), String::from(""), 0, &Some(mint_amount));
+
+    let reward = U256::from(1000);
+    reward_token.approve(invariant.address(), &reward);
+
+    let incentive_id = invariant
+        .create_incentive(
+            *reward_token.address(),
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            reward,
+            100_000,
+            200_000,
+        )
+        .unwrap();
+    assert_eq!(incentive_id, 0);
+
+    (invariant, reward_token, pool_key)
+}
+
+#[test]
+fn test_incentive() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, reward_token, pool_key) = init_incentive();
+    let initial_balance = reward_token.balance_of(&deployer);
+
+    // staked for half of the incentive, the other half is left for the refund
+    test_env::advance_block_time_by(150_000);
+    invariant.stake(0, 0).unwrap();
+
+    assert_eq!(
+        invariant.get_position_owner(0).unwrap(),
+        *invariant.address()
+    );
+    assert_eq!(invariant.get_stake(0).unwrap().owner, deployer);
+    assert_eq!(invariant.get_incentive(0).unwrap().number_of_stakes, 1);
+    assert_eq!(invariant.get_incentive(0).unwrap().pool_key, pool_key);
+
+    // anyone can unstake after the end
+    test_env::advance_block_time_by(50_000);
+    test_env::set_caller(test_env::get_account(1));
+    let reward = invariant.unstake(0).unwrap();
+    assert_eq!(reward, TokenAmount::new(U256::from(500)));
+
+    assert_eq!(invariant.get_position_owner(0).unwrap(), deployer);
+    assert_eq!(invariant.get_stake(0), Err(InvariantError::StakeNotFound));
+    assert_eq!(
+        invariant.get_reward(deployer, *reward_token.address()),
+        TokenAmount::new(U256::from(500))
+    );
+
+    test_env::set_caller(deployer);
+    let claimed = invariant.claim_reward(*reward_token.address()).unwrap();
+    assert_eq!(claimed, TokenAmount::new(U256::from(500)));
+    assert_eq!(
+        reward_token.balance_of(&deployer),
+        initial_balance + U256::from(500)
+    );
+
+    let refund = invariant.refund_incentive(0).unwrap();
+    assert_eq!(refund, TokenAmount::new(U256::from(500)));
+    assert_eq!(
+        reward_token.balance_of(&deployer),
+        initial_balance + U256::from(1000)
+    );
+    assert_eq!(
+        invariant.get_incentive(0).unwrap().total_reward_unclaimed,
+        TokenAmount::new(U256::from(0))
+    );
+}
+
+#[test]
+fn test_claim_fee_while_staked() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, pool_key) = init_incentive();
+    let token_x = Erc20Ref::at(&pool_key.token_x);
+
+    test_env::advance_block_time_by(100_000);
+    invariant.stake(0, 0).unwrap();
+
+    // the staked position keeps earning fees from swaps
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+        )
+        .unwrap();
+
+    let balance_x = token_x.balance_of(&deployer);
+    let (fee_x, fee_y) = invariant.claim_fee(0).unwrap();
+    assert!(!fee_x.get().is_zero());
+    assert!(fee_y.get().is_zero());
+    assert_eq!(token_x.balance_of(&deployer), balance_x + fee_x.get());
+
+    // the position stays staked with the contract
+    assert_eq!(
+        invariant.get_position_owner(0).unwrap(),
+        *invariant.address()
+    );
+    assert_eq!(invariant.get_stake(0).unwrap().owner, deployer);
+}
+
+#[test]
+#[should_panic]
+fn test_claim_fee_while_staked_not_staker() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _) = init_incentive();
+
+    test_env::advance_block_time_by(100_000);
+    invariant.stake(0, 0).unwrap();
+
+    test_env::set_caller(test_env::get_account(1));
+    let result = invariant.claim_fee(0);
+    assert_eq!(result, Err(InvariantError::NotPositionOwner));
+}
+
+#[test]
+#[should_panic]
+fn test_stake_before_start() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _) = init_incentive();
+
+    let result = invariant.stake(0, 0);
+    assert_eq!(result, Err(InvariantError::IncentiveNotActive));
+}
+
+#[test]
+#[should_panic]
+fn test_unstake_not_owner_before_end() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _) = init_incentive();
+
+    test_env::advance_block_time_by(100_000);
+    invariant.stake(0, 0).unwrap();
+
+    test_env::set_caller(test_env::get_account(1));
+    let result = invariant.unstake(0);
+    assert_eq!(result, Err(InvariantError::NotPositionOwner));
+}
+
+#[test]
+#[should_panic]
+fn test_refund_with_staked_positions() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _) = init_incentive();
+
+    test_env::advance_block_time_by(100_000);
+    invariant.stake(0, 0).unwrap();
+
+    test_env::advance_block_time_by(100_000);
+    let result = invariant.refund_incentive(0);
+    assert_eq!(result, Err(InvariantError::IncentiveNotEnded));
+}
@@ -116,7 +116,7 @@ fn test_interaction_with_pool_on_removed_fee_tier() {
 
         let pos = invariant.get_all_positions(deployer);
         assert_eq!(1, pos.len());
-        let position = invariant.get_position(deployer, 0).unwrap();
+        let position = invariant.get_position(0).unwrap();
         assert_eq!(position.liquidity, liquidity);
         assert_eq!(pool_after.liquidity, liquidity)
     }
@@ -319,23 +319,19 @@ fn test_interaction_with_pool_on_removed_fee_tier() {
 
         let recipient = test_env::get_account(1);
 
-        let transferred_index = 0;
+        let transferred_id = 1;
         let owner_list_before = invariant.get_all_positions(position_owner);
         test_env::set_caller(recipient);
         let recipient_list_before = invariant.get_all_positions(recipient);
         test_env::set_caller(position_owner);
-        let removed_position = invariant
-            .get_position(position_owner, transferred_index)
-            .unwrap();
+        let removed_position = invariant.get_position(transferred_id).unwrap();
 
         invariant
-            .transfer_position(transferred_index, recipient)
+            .transfer_position(transferred_id, recipient)
             .unwrap();
 
         test_env::set_caller(recipient);
-        let recipient_position = invariant
-            .get_position(recipient, transferred_index)
-            .unwrap();
+        let recipient_position = invariant.get_position(transferred_id).unwrap();
         let recipient_list_after = invariant.get_all_positions(recipient);
         test_env::set_caller(position_owner);
         let owner_positions_after = invariant.get_all_positions(position_owner);
@@ -0,0 +1,201 @@
+use crate::contracts::{InvariantError, PoolKey};
+use crate::e2e::snippets::init_basic_pool;
+use crate::math::liquidity::Liquidity;
+use crate::math::sqrt_price::SqrtPrice;
+use crate::math::token_amount::TokenAmount;
+use crate::math::MAX_SQRT_PRICE;
+use crate::{Erc20Ref, InvariantRef};
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+fn init_limit_order() -> (InvariantRef, Erc20Ref, Erc20Ref, PoolKey) {
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -100,
+            100,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    (invariant, token_x, token_y, pool_key)
+}
+
+fn place_order(invariant: &mut InvariantRef, pool_key: PoolKey, lower_tick: i32, x_to_y: bool) {
+    invariant
+        .place_limit_order(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            lower_tick,
+            x_to_y,
+            Liquidity::from_integer(1000000).get(),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_limit_order_filled_and_claimed() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_limit_order();
+
+    let balance_x_before = token_x.balance_of(&deployer);
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    // selling x in [10, 20] above the price
+    place_order(&mut invariant, pool_key, 10, true);
+
+    let order = invariant.get_limit_order(0).unwrap();
+    assert_eq!(order.owner, deployer);
+
+    let epoch = invariant.get_limit_order_epoch(order.epoch_id).unwrap();
+    assert!(!epoch.filled);
+    assert_eq!(
+        invariant.get_position_owner(epoch.position_id).unwrap(),
+        *invariant.address()
+    );
+    assert!(token_x.balance_of(&deployer) < balance_x_before);
+    assert_eq!(token_y.balance_of(&deployer), balance_y_before);
+
+    // buying x moves the price over the whole range
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            false,
+            U256::from(1800),
+            true,
+            U128::from(MAX_SQRT_PRICE),
+        )
+        .unwrap();
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert!(pool.current_tick_index >= 20);
+
+    let epoch = invariant.get_limit_order_epoch(order.epoch_id).unwrap();
+    assert!(epoch.filled);
+    assert!(epoch.amount_x.is_zero());
+    assert!(!epoch.amount_y.is_zero());
+    assert_eq!(
+        invariant.get_position(epoch.position_id),
+        Err(InvariantError::PositionNotFound)
+    );
+
+    let balance_y_before_claim = token_y.balance_of(&deployer);
+    let (x, y) = invariant.claim_limit_order(0).unwrap();
+
+    assert_eq!(x, TokenAmount::new(U256::from(0)));
+    assert_eq!(y, epoch.amount_y);
+    assert_eq!(
+        token_y.balance_of(&deployer),
+        balance_y_before_claim + y.get()
+    );
+    assert_eq!(
+        invariant.get_limit_order(0),
+        Err(InvariantError::LimitOrderNotFound)
+    );
+}
+
+#[test]
+fn test_cancel_limit_order() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_limit_order();
+
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    // selling y in [-20, -10] below the price, two orders share the range
+    place_order(&mut invariant, pool_key, -20, false);
+    place_order(&mut invariant, pool_key, -20, false);
+
+    assert_eq!(invariant.get_limit_order(1).unwrap().epoch_id, 0);
+    assert_eq!(
+        invariant.get_limit_order_epoch(0).unwrap().liquidity,
+        Liquidity::from_integer(2000000)
+    );
+
+    let balance_x_before_cancel = token_x.balance_of(&deployer);
+    invariant.cancel_limit_order(0).unwrap();
+    assert_eq!(
+        invariant.get_limit_order_epoch(0).unwrap().liquidity,
+        Liquidity::from_integer(1000000)
+    );
+
+    invariant.cancel_limit_order(1).unwrap();
+
+    // rounding in favor of the pool keeps at most a unit per deposit and withdrawal
+    assert_eq!(token_x.balance_of(&deployer), balance_x_before_cancel);
+    assert!(token_y.balance_of(&deployer) + U256::from(4) >= balance_y_before);
+
+    // the range is free for a new epoch
+    place_order(&mut invariant, pool_key, -20, false);
+    assert_eq!(invariant.get_limit_order(2).unwrap().epoch_id, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_place_limit_order_in_range() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_limit_order();
+
+    let result = invariant.place_limit_order(
+        pool_key.token_x,
+        pool_key.token_y,
+        pool_key.fee_tier.fee.get(),
+        pool_key.fee_tier.tick_spacing,
+        0,
+        true,
+        Liquidity::from_integer(1000000).get(),
+    );
+    assert_eq!(result, Err(InvariantError::InvalidLimitOrderTick));
+}
+
+#[test]
+#[should_panic]
+fn test_cancel_filled_limit_order() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_limit_order();
+
+    place_order(&mut invariant, pool_key, 10, true);
+
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            false,
+            U256::from(1800),
+            true,
+            U128::from(MAX_SQRT_PRICE),
+        )
+        .unwrap();
+
+    let result = invariant.cancel_limit_order(0);
+    assert_eq!(result, Err(InvariantError::LimitOrderFilled));
+}
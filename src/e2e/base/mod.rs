@@ -1,20 +1,40 @@
+pub mod access_control;
 pub mod add_fee_tier;
+pub mod amount_slippage;
 pub mod change_fee_receiver;
+pub mod change_liquidity;
 pub mod change_protocol_fee;
 pub mod claim;
 pub mod constructor;
 pub mod create_pool;
 pub mod cross;
 pub mod cross_both_side;
+pub mod deadline;
+pub mod flash;
+pub mod flash_swap;
+pub mod incentives;
 pub mod interaction_with_pool_on_removed_fee_tier;
+pub mod limit_order;
 pub mod limits;
 pub mod liquidity_gap;
+pub mod multicall;
 pub mod multiple_swap;
+pub mod oracle;
+pub mod pagination;
+pub mod partial_fill;
+pub mod pause;
+pub mod pool_protocol_fee;
 pub mod position;
+pub mod position_approvals;
+pub mod position_by_amounts;
 pub mod position_list;
 pub mod position_slippage;
 pub mod protocol_fee;
+pub mod recipient;
 pub mod remove_fee_tier;
+pub mod seconds_per_liquidity;
 pub mod slippage;
 pub mod swap;
 pub mod swap_route;
+pub mod swap_to_price;
+pub mod tick_queries;
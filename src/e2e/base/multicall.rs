@@ -0,0 +1,109 @@
+use crate::contracts::{Call, CallResult, FeeTier, PoolKey};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::token_amount::TokenAmount;
+use crate::math::MIN_SQRT_PRICE;
+use alloc::vec;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_multicall() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let results = invariant
+        .multicall(vec![
+            Call::CreatePosition(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                -20,
+                10,
+                Liquidity::from_integer(1000000).get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            ),
+            Call::Swap(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                true,
+                U256::from(1000),
+                true,
+                U128::from(MIN_SQRT_PRICE),
+            ),
+            Call::ClaimFee(0),
+        ])
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+
+    match &results[0] {
+        CallResult::CreatePosition(position) => {
+            assert_eq!(position.id, 0);
+            assert_eq!(position.liquidity, Liquidity::from_integer(1000000));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    match &results[1] {
+        CallResult::Swap(swap) => {
+            assert_eq!(swap.amount_out, TokenAmount::new(U256::from(993)));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    assert_eq!(
+        results[2],
+        CallResult::ClaimFee(
+            TokenAmount::new(U256::from(5)),
+            TokenAmount::new(U256::from(0))
+        )
+    );
+
+    // 500 x deposited, 1000 x swapped in and 5 x of fees claimed back
+    assert_eq!(token_x.balance_of(invariant.address()), U256::from(1495));
+    assert_eq!(token_y.balance_of(invariant.address()), U256::from(7));
+}
+
+#[test]
+#[should_panic]
+fn test_multicall_is_atomic() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _) = init(Percentage::from_scale(1, 2), U256::from(10u128.pow(10)));
+
+    // claiming fees of a missing position fails the whole batch
+    invariant.multicall(vec![Call::ClaimFee(0)]).unwrap();
+}
@@ -0,0 +1,186 @@
+use crate::contracts::{FeeTier, InvariantError, PoolKey};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::MIN_SQRT_PRICE;
+use alloc::vec;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_observe() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    invariant
+        .increase_observation_cardinality(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            4,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    test_env::advance_block_time_by(100);
+
+    let swapper = test_env::get_account(1);
+    let amount = U256::from(1000);
+    token_x.mint(&swapper, &amount);
+    test_env::set_caller(swapper);
+    token_x.approve(invariant.address(), &amount);
+
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            true,
+            amount,
+            true,
+            SqrtPrice::new(U128::from(MIN_SQRT_PRICE)).get(),
+        )
+        .unwrap();
+
+    test_env::advance_block_time_by(100);
+
+    let result = invariant
+        .observe(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            vec![200, 100, 50, 0],
+        )
+        .unwrap();
+
+    assert_eq!(result.tick_cumulatives, vec![0, 0, -1000, -2000]);
+    assert_eq!(result.average_ticks, vec![0, -20, -20]);
+    assert_eq!(
+        result.average_sqrt_prices,
+        vec![
+            calculate_sqrt_price(0).unwrap(),
+            calculate_sqrt_price(-20).unwrap(),
+            calculate_sqrt_price(-20).unwrap(),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_observe_too_old() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, token_x, token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    test_env::advance_block_time_by(100);
+
+    let result = invariant.observe(
+        pool_key.token_x,
+        pool_key.token_y,
+        fee_tier.fee.get(),
+        fee_tier.tick_spacing,
+        vec![101],
+    );
+    assert_eq!(result, Err(InvariantError::ObservationTooOld));
+}
+
+#[test]
+#[should_panic]
+fn test_observe_invalid_window() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, token_x, token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    test_env::advance_block_time_by(100);
+
+    let result = invariant.observe(
+        pool_key.token_x,
+        pool_key.token_y,
+        fee_tier.fee.get(),
+        fee_tier.tick_spacing,
+        vec![0, 50],
+    );
+    assert_eq!(result, Err(InvariantError::InvalidObservationWindow));
+}
@@ -0,0 +1,85 @@
+use crate::contracts::{FeeTier, PoolKey};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use alloc::vec;
+use decimal::{Decimal, Factories};
+use odra::prelude::vec::Vec;
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_pagination() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tiers: Vec<FeeTier> = [1, 2, 10]
+        .iter()
+        .map(|tick_spacing| FeeTier::new(Percentage::from_scale(6, 3), *tick_spacing).unwrap())
+        .collect();
+
+    for fee_tier in fee_tiers.iter() {
+        invariant
+            .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+            .unwrap();
+    }
+
+    assert_eq!(
+        invariant.get_fee_tiers_paginated(0, 2),
+        (vec![fee_tiers[0], fee_tiers[1]], 3)
+    );
+    assert_eq!(
+        invariant.get_fee_tiers_paginated(2, 2),
+        (vec![fee_tiers[2]], 3)
+    );
+
+    let pool_keys: Vec<PoolKey> = fee_tiers
+        .iter()
+        .map(|fee_tier| PoolKey::new(*token_x.address(), *token_y.address(), *fee_tier).unwrap())
+        .collect();
+
+    for pool_key in pool_keys.iter() {
+        invariant
+            .create_pool(
+                pool_key.token_x,
+                pool_key.token_y,
+                pool_key.fee_tier.fee.get(),
+                pool_key.fee_tier.tick_spacing,
+                calculate_sqrt_price(0).unwrap().get(),
+                0,
+            )
+            .unwrap();
+    }
+
+    assert_eq!(invariant.get_pools_paginated(1, 1), (vec![pool_keys[1]], 3));
+    assert_eq!(invariant.get_pools_paginated(3, 1), (vec![], 3));
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    for pool_key in pool_keys.iter() {
+        invariant
+            .create_position(
+                pool_key.token_x,
+                pool_key.token_y,
+                pool_key.fee_tier.fee.get(),
+                pool_key.fee_tier.tick_spacing,
+                -10,
+                10,
+                Liquidity::from_integer(1000).get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            )
+            .unwrap();
+    }
+
+    let (positions, count) = invariant.get_positions_paginated(deployer, 1, 5);
+    assert_eq!(count, 3);
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions[0].pool_key, pool_keys[1]);
+    assert_eq!(positions[1].pool_key, pool_keys[2]);
+}
@@ -0,0 +1,93 @@
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::sqrt_price::calculate_sqrt_price;
+use decimal::Decimal;
+use odra::test_env;
+use odra::types::U256;
+
+#[test]
+fn test_swap_with_partial_fill() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let amount = U256::from(1000);
+    let sqrt_price_limit = calculate_sqrt_price(-10).unwrap();
+
+    let quote = invariant
+        .quote_with_partial_fill(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            amount,
+            true,
+            sqrt_price_limit.get(),
+        )
+        .unwrap();
+    assert!(quote.amount_in.get() < amount);
+    assert_eq!(quote.target_sqrt_price, sqrt_price_limit);
+
+    let balance_x_before = token_x.balance_of(&deployer);
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    let result = invariant
+        .swap_with_partial_fill(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            amount,
+            true,
+            sqrt_price_limit.get(),
+        )
+        .unwrap();
+
+    assert_eq!(result.amount_in, quote.amount_in);
+    assert_eq!(result.amount_out, quote.amount_out);
+    assert_eq!(
+        balance_x_before - token_x.balance_of(&deployer),
+        result.amount_in.get()
+    );
+    assert_eq!(
+        token_y.balance_of(&deployer) - balance_y_before,
+        result.amount_out.get()
+    );
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool.sqrt_price, sqrt_price_limit);
+    assert_eq!(pool.current_tick_index, -10);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_without_partial_fill_reaches_price_limit() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            calculate_sqrt_price(-10).unwrap().get(),
+        )
+        .unwrap();
+}
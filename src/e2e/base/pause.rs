@@ -0,0 +1,314 @@
+use crate::contracts::{
+    ChangePauseStatusEvent, ChangePoolPauseStatusEvent, FeeTier, InvariantError, PauseKind,
+    PauseStatus, PoolKey, Role,
+};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::MIN_SQRT_PRICE;
+use decimal::{Decimal, Factories};
+use odra::assert_events;
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_pause_everything_keeps_exit_available() {
+    let deployer = test_env::get_account(0);
+    let pauser = test_env::get_account(1);
+    let swapper = test_env::get_account(2);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    let amount = U256::from(1000);
+    token_x.mint(&swapper, &amount);
+    test_env::set_caller(swapper);
+    token_x.approve(invariant.address(), &amount);
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            true,
+            amount,
+            true,
+            SqrtPrice::new(U128::from(MIN_SQRT_PRICE)).get(),
+        )
+        .unwrap();
+
+    test_env::set_caller(deployer);
+    invariant.grant_role(Role::Pauser, pauser).unwrap();
+
+    test_env::set_caller(pauser);
+    invariant.pause(PauseKind::All).unwrap();
+    assert_eq!(
+        invariant.get_pause_status(),
+        PauseStatus {
+            swaps: true,
+            positions: true
+        }
+    );
+
+    // LP can still exit
+    test_env::set_caller(deployer);
+    let (claimed_x, _) = invariant.claim_fee(0).unwrap();
+    assert_eq!(claimed_x.get(), U256::from(5));
+    invariant.remove_position(0).unwrap();
+    assert_eq!(invariant.get_all_positions(deployer).len(), 0);
+
+    test_env::set_caller(pauser);
+    invariant.unpause(PauseKind::All).unwrap();
+    assert_eq!(invariant.get_pause_status(), PauseStatus::default());
+
+    test_env::set_caller(deployer);
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_swap_globally_paused() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    invariant.pause(PauseKind::Swaps).unwrap();
+
+    let result = invariant.swap(
+        pool_key.token_x,
+        pool_key.token_y,
+        fee_tier.fee.get(),
+        fee_tier.tick_spacing,
+        true,
+        U256::from(1000),
+        true,
+        SqrtPrice::new(U128::from(MIN_SQRT_PRICE)).get(),
+    );
+    assert_eq!(result, Err(InvariantError::Paused));
+}
+
+#[test]
+#[should_panic]
+fn test_create_position_in_paused_pool() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    invariant
+        .pause_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            PauseKind::Positions,
+        )
+        .unwrap();
+    assert_events!(
+        invariant,
+        ChangePoolPauseStatusEvent {
+            timestamp: 0,
+            address: deployer,
+            pool: pool_key,
+            pause_status: PauseStatus {
+                swaps: false,
+                positions: true
+            },
+        }
+    );
+    assert_eq!(
+        invariant.get_pool_pause_status(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+        ),
+        PauseStatus {
+            swaps: false,
+            positions: true
+        }
+    );
+    assert_eq!(invariant.get_pause_status(), PauseStatus::default());
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let result = invariant.create_position(
+        pool_key.token_x,
+        pool_key.token_y,
+        fee_tier.fee.get(),
+        fee_tier.tick_spacing,
+        -20,
+        10,
+        Liquidity::from_integer(1000000).get(),
+        SqrtPrice::new(U128::from(0)).get(),
+        SqrtPrice::max_instance().get(),
+    );
+    assert_eq!(result, Err(InvariantError::Paused));
+}
+
+#[test]
+fn test_pause_and_unpause_events() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, _, _) = init(fee, mint_amount);
+
+    invariant.pause(PauseKind::All).unwrap();
+    assert_events!(
+        invariant,
+        ChangePauseStatusEvent {
+            timestamp: 0,
+            address: deployer,
+            pause_status: PauseStatus {
+                swaps: true,
+                positions: true
+            },
+        }
+    );
+
+    invariant.unpause(PauseKind::Swaps).unwrap();
+    assert_events!(
+        invariant,
+        ChangePauseStatusEvent {
+            timestamp: 0,
+            address: deployer,
+            pause_status: PauseStatus {
+                swaps: false,
+                positions: true
+            },
+        }
+    );
+    assert_eq!(
+        invariant.get_pause_status(),
+        PauseStatus {
+            swaps: false,
+            positions: true
+        }
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_pause_not_pauser() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, _, _) = init(fee, mint_amount);
+
+    test_env::set_caller(test_env::get_account(1));
+    let result = invariant.pause(PauseKind::All);
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
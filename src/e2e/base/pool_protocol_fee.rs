@@ -0,0 +1,178 @@
+use crate::contracts::{FeeTier, InvariantError, PoolKey};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::token_amount::TokenAmount;
+use crate::math::MIN_SQRT_PRICE;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_pool_protocol_fee() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let protocol_fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(protocol_fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    let pool_protocol_fee = invariant
+        .get_pool_protocol_fee(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool_protocol_fee, protocol_fee);
+
+    invariant
+        .change_pool_protocol_fee(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            Percentage::new(U128::from(0)).get(),
+        )
+        .unwrap();
+
+    let pool_protocol_fee = invariant
+        .get_pool_protocol_fee(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool_protocol_fee, Percentage::new(U128::from(0)));
+    assert_eq!(invariant.get_protocol_fee(), protocol_fee);
+
+    // Swap without protocol fee
+    {
+        let swapper = test_env::get_account(1);
+        let amount = U256::from(1000);
+        token_x.mint(&swapper, &amount);
+        test_env::set_caller(swapper);
+        token_x.approve(invariant.address(), &amount);
+
+        invariant
+            .swap(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                true,
+                amount,
+                true,
+                SqrtPrice::new(U128::from(MIN_SQRT_PRICE)).get(),
+            )
+            .unwrap();
+
+        let pool = invariant
+            .get_pool(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+
+        assert_eq!(pool.fee_protocol_token_x, TokenAmount::new(U256::from(0)));
+        assert_eq!(pool.fee_protocol_token_y, TokenAmount::new(U256::from(0)));
+    }
+
+    test_env::set_caller(deployer);
+    invariant
+        .reset_pool_protocol_fee(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+        )
+        .unwrap();
+
+    let pool_protocol_fee = invariant
+        .get_pool_protocol_fee(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool_protocol_fee, protocol_fee);
+}
+
+#[test]
+#[should_panic]
+fn test_change_pool_protocol_fee_not_admin() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, token_x, token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    test_env::set_caller(test_env::get_account(1));
+    let result = invariant.change_pool_protocol_fee(
+        pool_key.token_x,
+        pool_key.token_y,
+        fee_tier.fee.get(),
+        fee_tier.tick_spacing,
+        Percentage::new(U128::from(0)).get(),
+    );
+    assert_eq!(result, Err(InvariantError::NotAdmin));
+}
@@ -144,7 +144,7 @@ fn test_remove_position() {
     let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
 
     let init_tick = 0;
-    let remove_position_index = 0;
+    let remove_position_id = 0;
 
     invariant
         .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
@@ -236,7 +236,7 @@ fn test_remove_position() {
             )
             .unwrap();
 
-        let position_state = invariant.get_position(position_owner, 1).unwrap();
+        let position_state = invariant.get_position(1).unwrap();
 
         assert_events!(
             invariant,
@@ -330,7 +330,7 @@ fn test_remove_position() {
 
     // Remove position
     test_env::set_caller(position_owner);
-    invariant.remove_position(remove_position_index).unwrap();
+    invariant.remove_position(remove_position_id).unwrap();
 
     // Load states
     let pool_state = invariant
@@ -475,7 +475,7 @@ fn test_position_within_current_tick() {
         .unwrap();
 
     // Load states
-    let position_state = invariant.get_position(position_owner, 0).unwrap();
+    let position_state = invariant.get_position(0).unwrap();
     let pool_state = invariant
         .get_pool(
             pool_key.token_x,
@@ -621,7 +621,7 @@ fn test_position_below_current_tick() {
         .unwrap();
 
     // Load states
-    let position_state = invariant.get_position(position_owner, 0).unwrap();
+    let position_state = invariant.get_position(0).unwrap();
     let pool_state = invariant
         .get_pool(
             pool_key.token_x,
@@ -766,7 +766,7 @@ fn test_position_above_current_tick() {
         .unwrap();
 
     // Load states
-    let position_state = invariant.get_position(position_owner, 0).unwrap();
+    let position_state = invariant.get_position(0).unwrap();
     let pool_state = invariant
         .get_pool(
             pool_key.token_x,
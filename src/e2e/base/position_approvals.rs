@@ -0,0 +1,321 @@
+use crate::contracts::{FeeTier, InvariantError, PoolKey};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::MIN_SQRT_PRICE;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_operator_manages_approved_position() {
+    let position_owner = test_env::get_account(0);
+    let operator = test_env::get_account(1);
+    let swapper = test_env::get_account(2);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let liquidity_delta = Liquidity::from_integer(1000000);
+
+    let position = invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            liquidity_delta.get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    assert!(!invariant.is_approved(position.id, operator).unwrap());
+    invariant.approve_position(position.id, operator).unwrap();
+    assert!(invariant.is_approved(position.id, operator).unwrap());
+
+    // Generate fees
+    {
+        let amount = U256::from(1000);
+        token_x.mint(&swapper, &amount);
+        test_env::set_caller(swapper);
+        token_x.approve(invariant.address(), &amount);
+
+        invariant
+            .swap(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                true,
+                amount,
+                true,
+                SqrtPrice::new(U128::from(MIN_SQRT_PRICE)).get(),
+            )
+            .unwrap();
+    }
+    // Operator claims fees on behalf of the owner
+    {
+        test_env::set_caller(operator);
+        let owner_x_before = token_x.balance_of(&position_owner);
+
+        let (claimed_x, claimed_y) = invariant.claim_fee(position.id).unwrap();
+
+        assert_eq!(claimed_x.get(), U256::from(5));
+        assert!(claimed_y.get().is_zero());
+        assert_eq!(
+            token_x.balance_of(&position_owner),
+            owner_x_before + claimed_x.get()
+        );
+        assert_eq!(token_x.balance_of(&operator), U256::from(0));
+    }
+    // Operator removes the position, tokens go to the owner
+    {
+        let owner_x_before = token_x.balance_of(&position_owner);
+        let owner_y_before = token_y.balance_of(&position_owner);
+
+        let (amount_x, amount_y) = invariant.remove_position(position.id).unwrap();
+
+        assert_eq!(
+            token_x.balance_of(&position_owner),
+            owner_x_before + amount_x.get()
+        );
+        assert_eq!(
+            token_y.balance_of(&position_owner),
+            owner_y_before + amount_y.get()
+        );
+        assert_eq!(token_x.balance_of(&operator), U256::from(0));
+        assert_eq!(token_y.balance_of(&operator), U256::from(0));
+        assert_eq!(invariant.get_all_positions(position_owner).len(), 0);
+    }
+}
+
+#[test]
+fn test_operator_approved_for_all_positions() {
+    let position_owner = test_env::get_account(0);
+    let operator = test_env::get_account(1);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let liquidity_delta = Liquidity::from_integer(1000000);
+
+    for _ in 0..2 {
+        invariant
+            .create_position(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                -20,
+                10,
+                liquidity_delta.get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            )
+            .unwrap();
+    }
+
+    invariant.approve_operator(operator);
+    assert!(invariant.is_approved(0, operator).unwrap());
+    assert!(invariant.is_approved(1, operator).unwrap());
+
+    // Operator decreases liquidity, tokens go to the owner
+    {
+        let owner_x_before = token_x.balance_of(&position_owner);
+        let owner_y_before = token_y.balance_of(&position_owner);
+
+        test_env::set_caller(operator);
+        let (amount_x, amount_y) = invariant
+            .decrease_liquidity(
+                1,
+                Liquidity::from_integer(500000).get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            token_x.balance_of(&position_owner),
+            owner_x_before + amount_x.get()
+        );
+        assert_eq!(
+            token_y.balance_of(&position_owner),
+            owner_y_before + amount_y.get()
+        );
+        assert_eq!(
+            invariant.get_position(1).unwrap().liquidity,
+            Liquidity::from_integer(500000)
+        );
+    }
+    // Revoked operator loses access
+    {
+        test_env::set_caller(position_owner);
+        invariant.revoke_operator(operator);
+
+        assert!(!invariant.is_approved(0, operator).unwrap());
+        assert!(!invariant.is_approved(1, operator).unwrap());
+
+        test_env::set_caller(operator);
+        let result = invariant.claim_fee(0);
+        assert_eq!(result, Err(InvariantError::NotPositionOwner));
+    }
+}
+
+#[test]
+fn test_position_approval_revoked_and_cleared_on_transfer() {
+    let position_owner = test_env::get_account(0);
+    let operator = test_env::get_account(1);
+    let recipient = test_env::get_account(2);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    invariant.approve_position(0, operator).unwrap();
+    invariant.revoke_position(0).unwrap();
+    assert!(!invariant.is_approved(0, operator).unwrap());
+
+    invariant.approve_position(0, operator).unwrap();
+    invariant.transfer_position(0, recipient).unwrap();
+    assert!(!invariant.is_approved(0, operator).unwrap());
+    assert!(invariant.is_approved(0, recipient).unwrap());
+    assert!(!invariant.is_approved(0, position_owner).unwrap());
+}
+
+#[test]
+#[should_panic]
+fn test_operator_cannot_transfer_position() {
+    let position_owner = test_env::get_account(0);
+    let operator = test_env::get_account(1);
+    test_env::set_caller(position_owner);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let fee = Percentage::from_scale(1, 2);
+    let (mut invariant, mut token_x, mut token_y) = init(fee, mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    invariant.approve_operator(operator);
+
+    test_env::set_caller(operator);
+    invariant.transfer_position(0, operator).unwrap();
+}
@@ -0,0 +1,170 @@
+use crate::contracts::get_max_liquidity;
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::token_amount::TokenAmount;
+use crate::math::MAX_TICK;
+use decimal::Decimal;
+use odra::test_env;
+use odra::types::U256;
+
+#[test]
+fn test_create_position_by_amounts() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+    let amount_x_desired = U256::from(500);
+    let amount_y_desired = U256::from(2000);
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    let expected_liquidity = get_max_liquidity(
+        TokenAmount::new(amount_x_desired),
+        TokenAmount::new(amount_y_desired),
+        -20,
+        10,
+        pool.current_tick_index,
+        pool.sqrt_price,
+    )
+    .unwrap();
+
+    let position = invariant
+        .create_position_by_amounts(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            10,
+            amount_x_desired,
+            amount_y_desired,
+            U256::from(499),
+            U256::from(0),
+        )
+        .unwrap();
+    assert_eq!(position.liquidity, expected_liquidity);
+
+    // token x limits the liquidity, token y is not used up
+    let amount_x = token_x.balance_of(invariant.address());
+    let amount_y = token_y.balance_of(invariant.address());
+    assert!(amount_x <= amount_x_desired && amount_x >= U256::from(499));
+    assert!(amount_y < amount_y_desired);
+}
+
+#[test]
+fn test_create_position_by_amounts_with_exact_amounts() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+
+    // the amounts charged for a position opened by liquidity are passed back as the exact desired amounts
+    init_basic_position(&mut invariant, pool_key);
+    let amount_x_desired = token_x.balance_of(invariant.address());
+    let amount_y_desired = token_y.balance_of(invariant.address());
+
+    let position = invariant
+        .create_position_by_amounts(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            10,
+            amount_x_desired,
+            amount_y_desired,
+            U256::from(0),
+            U256::from(0),
+        )
+        .unwrap();
+    assert!(!position.liquidity.get().is_zero());
+
+    // rounding up the provided amounts never exceeds the desired ones
+    let amount_x = token_x.balance_of(invariant.address()) - amount_x_desired;
+    let amount_y = token_y.balance_of(invariant.address()) - amount_y_desired;
+    assert!(amount_x <= amount_x_desired && !amount_x.is_zero());
+    assert!(amount_y <= amount_y_desired && !amount_y.is_zero());
+}
+
+#[test]
+fn test_create_position_by_amounts_price_at_lower_tick() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+
+    let position = invariant
+        .create_position_by_amounts(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            0,
+            10,
+            U256::from(1000),
+            U256::from(1000),
+            U256::from(0),
+            U256::from(0),
+        )
+        .unwrap();
+    assert!(!position.liquidity.get().is_zero());
+
+    // only token x is needed while the price rests on the lower tick
+    let amount_x = token_x.balance_of(invariant.address());
+    let amount_y = token_y.balance_of(invariant.address());
+    assert!(amount_x <= U256::from(1000) && !amount_x.is_zero());
+    assert_eq!(amount_y, U256::from(0));
+}
+
+#[test]
+#[should_panic]
+fn test_create_position_by_amounts_below_minimum() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+
+    invariant
+        .create_position_by_amounts(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            10,
+            U256::from(100),
+            U256::from(1000),
+            U256::from(0),
+            U256::from(1000),
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_create_position_by_amounts_invalid_ticks() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+
+    invariant
+        .create_position_by_amounts(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            MAX_TICK + 10,
+            U256::from(1000),
+            U256::from(1000),
+            U256::from(0),
+            U256::from(0),
+        )
+        .unwrap();
+}
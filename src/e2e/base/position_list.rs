@@ -1,4 +1,4 @@
-use crate::contracts::{FeeTier, InvariantError, PoolKey};
+use crate::contracts::{FeeTier, InvariantError, PoolKey, TransferPositionEvent};
 use crate::e2e::snippets::positions_equals;
 use crate::math::fee_growth::FeeGrowth;
 use crate::math::liquidity::Liquidity;
@@ -6,7 +6,9 @@ use crate::math::percentage::Percentage;
 use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
 use crate::Erc20Deployer;
 use crate::InvariantDeployer;
+use alloc::vec;
 use decimal::{Decimal, Factories};
+use odra::assert_events;
 use odra::prelude::string::String;
 use odra::test_env;
 use odra::types::{U128, U256};
@@ -170,16 +172,21 @@ fn test_add_multiple_positions() {
 
     // Remove middle position
     {
-        let position_index_to_remove = 2;
+        let position_id_to_remove = 2;
         let positions_list_before = invariant.get_all_positions(positions_owner);
         let last_position = positions_list_before[positions_list_before.len() - 1];
 
-        invariant.remove_position(position_index_to_remove).unwrap();
+        invariant.remove_position(position_id_to_remove).unwrap();
 
         let positions_list_after = invariant.get_all_positions(positions_owner);
-        let tested_position = positions_list_after[position_index_to_remove as usize];
+        let tested_position = positions_list_after[position_id_to_remove as usize];
 
-        // Last position should be at removed index
+        // Last position should be at removed index and keep its id
+        assert_eq!(
+            invariant.get_position(position_id_to_remove),
+            Err(InvariantError::PositionNotFound)
+        );
+        assert_eq!(last_position.id, tested_position.id);
         assert_eq!(last_position.pool_key, tested_position.pool_key);
         assert_eq!(last_position.liquidity, tested_position.liquidity);
         assert_eq!(
@@ -224,22 +231,20 @@ fn test_add_multiple_positions() {
     }
     // Remove last position
     {
-        let last_position_index_before = invariant.get_all_positions(positions_owner).len() - 1;
+        let position_ids_before = invariant.get_position_ids(positions_owner);
+        let last_position_id = position_ids_before[position_ids_before.len() - 1];
 
-        invariant
-            .remove_position(last_position_index_before as u32)
-            .unwrap();
+        invariant.remove_position(last_position_id).unwrap();
 
-        let last_position_index_after = invariant.get_all_positions(positions_owner).len() - 1;
+        let position_ids_after = invariant.get_position_ids(positions_owner);
 
-        assert_eq!(last_position_index_before - 1, last_position_index_after)
+        assert_eq!(position_ids_before.len() - 1, position_ids_after.len());
+        assert!(!position_ids_after.contains(&last_position_id));
     }
     // Remove all positions
     {
-        let last_position_index = invariant.get_all_positions(positions_owner).len();
-
-        for i in (0..last_position_index).rev() {
-            invariant.remove_position(i as u32).unwrap();
+        for id in invariant.get_position_ids(positions_owner) {
+            invariant.remove_position(id).unwrap();
         }
 
         let list_length = invariant.get_all_positions(positions_owner).len();
@@ -383,14 +388,14 @@ fn test_only_owner_can_modify_position_list() {
 
     // Remove middle position
     {
-        let position_index_to_remove = 2;
+        let position_id_to_remove = 2;
         let positions_list_before = invariant.get_all_positions(positions_owner);
         let last_position = positions_list_before[positions_list_before.len() - 1];
 
-        invariant.remove_position(position_index_to_remove).unwrap();
+        invariant.remove_position(position_id_to_remove).unwrap();
 
         let positions_list_after = invariant.get_all_positions(positions_owner);
-        let tested_position = positions_list_after[position_index_to_remove as usize];
+        let tested_position = positions_list_after[position_id_to_remove as usize];
 
         // Last position should be at removed index
         assert!(positions_equals(last_position, tested_position));
@@ -418,12 +423,13 @@ fn test_only_owner_can_modify_position_list() {
     }
     // Remove last position
     {
-        let last_position_index_before = invariant.get_all_positions(positions_owner).len() - 1;
+        let position_ids = invariant.get_position_ids(positions_owner);
+        let last_position_id = position_ids[position_ids.len() - 1];
 
         let unauthorized_user = test_env::get_account(1);
         test_env::set_caller(unauthorized_user);
-        let result = invariant.remove_position(last_position_index_before as u32);
-        assert_eq!(result, Err(InvariantError::PositionNotFound));
+        let result = invariant.remove_position(last_position_id);
+        assert_eq!(result, Err(InvariantError::NotPositionOwner));
     }
 }
 
@@ -544,33 +550,31 @@ fn test_transfer_position_ownership() {
     }
     // Transfer first position
     {
-        let transferred_index = 0;
+        let transferred_id = 0;
         let owner_list_before = invariant.get_all_positions(positions_owner);
         test_env::set_caller(recipient);
         let recipient_list_before = invariant.get_all_positions(recipient);
         test_env::set_caller(positions_owner);
-        let removed_position = invariant
-            .get_position(positions_owner, transferred_index)
-            .unwrap();
+        let removed_position = invariant.get_position(transferred_id).unwrap();
         let last_position_before = owner_list_before[owner_list_before.len() - 1];
 
         invariant
-            .transfer_position(transferred_index, recipient)
+            .transfer_position(transferred_id, recipient)
             .unwrap();
 
         test_env::set_caller(recipient);
-        let recipient_position = invariant
-            .get_position(recipient, transferred_index)
-            .unwrap();
+        let recipient_position = invariant.get_position(transferred_id).unwrap();
         let recipient_list_after = invariant.get_all_positions(recipient);
         test_env::set_caller(positions_owner);
-        let owner_first_position_after = invariant
-            .get_position(positions_owner, transferred_index)
-            .unwrap();
         let owner_list_after = invariant.get_all_positions(positions_owner);
+        let owner_first_position_after = owner_list_after[0];
 
         assert_eq!(recipient_list_after.len(), recipient_list_before.len() + 1);
         assert_eq!(owner_list_before.len() - 1, owner_list_after.len());
+        assert_eq!(
+            invariant.get_position_owner(transferred_id).unwrap(),
+            recipient
+        );
 
         // move last position
         assert!(positions_equals(
@@ -579,80 +583,79 @@ fn test_transfer_position_ownership() {
         ));
 
         // Equals fields od transferred position
-        assert!(positions_equals(recipient_position, removed_position));
+        assert_eq!(recipient_position, removed_position);
+
+        assert_events!(
+            invariant,
+            TransferPositionEvent {
+                timestamp: 0,
+                address: positions_owner,
+                receiver: recipient,
+                position_id: transferred_id,
+                pool: pool_key,
+            }
+        );
     }
 
     // Transfer middle position
     {
-        let transferred_index = 1;
         let owner_list_before = invariant.get_all_positions(positions_owner);
+        let transferred_id = owner_list_before[1].id;
         test_env::set_caller(recipient);
         let recipient_list_before = invariant.get_all_positions(recipient);
         let last_position_before = owner_list_before[owner_list_before.len() - 1];
 
         test_env::set_caller(positions_owner);
         invariant
-            .transfer_position(transferred_index, recipient)
+            .transfer_position(transferred_id, recipient)
             .unwrap();
 
         let owner_list_after = invariant.get_all_positions(positions_owner);
         test_env::set_caller(recipient);
         let recipient_list_after = invariant.get_all_positions(recipient);
         test_env::set_caller(positions_owner);
-        let owner_first_position_after = invariant
-            .get_position(positions_owner, transferred_index)
-            .unwrap();
+        let owner_middle_position_after = owner_list_after[1];
 
         assert_eq!(recipient_list_after.len(), recipient_list_before.len() + 1);
         assert_eq!(owner_list_before.len() - 1, owner_list_after.len());
 
         // move last position
         assert!(positions_equals(
-            owner_first_position_after,
+            owner_middle_position_after,
             last_position_before
         ));
     }
     // Transfer last position
     {
         let owner_list_before = invariant.get_all_positions(positions_owner);
-        let transferred_index = (owner_list_before.len() - 1) as u32;
-        let removed_position = invariant
-            .get_position(positions_owner, transferred_index)
-            .unwrap();
+        let transferred_id = owner_list_before[owner_list_before.len() - 1].id;
+        let removed_position = invariant.get_position(transferred_id).unwrap();
 
         invariant
-            .transfer_position(transferred_index, recipient)
+            .transfer_position(transferred_id, recipient)
             .unwrap();
 
         test_env::set_caller(recipient);
         let recipient_list_after = invariant.get_all_positions(recipient);
-        let recipient_position_index = (recipient_list_after.len() - 1) as u32;
-        let recipient_position = invariant
-            .get_position(recipient, recipient_position_index)
-            .unwrap();
+        let recipient_position = recipient_list_after[recipient_list_after.len() - 1];
 
-        assert!(positions_equals(removed_position, recipient_position));
+        assert_eq!(removed_position, recipient_position);
     }
 
     // Clear position
     {
-        let transferred_index = 0;
         let recipient_list_before = invariant.get_all_positions(recipient);
         test_env::set_caller(positions_owner);
-        let removed_position = invariant
-            .get_position(positions_owner, transferred_index)
-            .unwrap();
+        let transferred_id = invariant.get_position_ids(positions_owner)[0];
+        let removed_position = invariant.get_position(transferred_id).unwrap();
 
         invariant
-            .transfer_position(transferred_index, recipient)
+            .transfer_position(transferred_id, recipient)
             .unwrap();
 
         test_env::set_caller(recipient);
         let recipient_list_after = invariant.get_all_positions(recipient);
-        let recipient_position_index = (recipient_list_after.len() - 1) as u32;
-        let recipient_position = invariant
-            .get_position(recipient, recipient_position_index)
-            .unwrap();
+        let recipient_position = recipient_list_after[recipient_list_after.len() - 1];
         test_env::set_caller(positions_owner);
         let owner_list_after = invariant.get_all_positions(positions_owner);
 
@@ -660,39 +663,37 @@ fn test_transfer_position_ownership() {
         assert_eq!(0, owner_list_after.len());
 
         // Equals fields od transferred position
-        assert!(positions_equals(recipient_position, removed_position));
+        assert_eq!(recipient_position, removed_position);
     }
 
     // Get back position
     {
-        let transferred_index = 0;
         let owner_list_before = invariant.get_all_positions(positions_owner);
         test_env::set_caller(recipient);
         let recipient_list_before = invariant.get_all_positions(recipient);
-        let removed_position = invariant
-            .get_position(recipient, transferred_index)
-            .unwrap();
+        let transferred_id = recipient_list_before[0].id;
+        let removed_position = invariant.get_position(transferred_id).unwrap();
         let last_position_before = recipient_list_before[recipient_list_before.len() - 1];
 
         invariant
-            .transfer_position(transferred_index, positions_owner)
+            .transfer_position(transferred_id, positions_owner)
             .unwrap();
 
         test_env::set_caller(positions_owner);
         let owner_list_after = invariant.get_all_positions(positions_owner);
         test_env::set_caller(recipient);
         let recipient_list_after = invariant.get_all_positions(recipient);
-        let recipient_first_position_after = invariant
-            .get_position(recipient, transferred_index)
-            .unwrap();
+        let recipient_first_position_after = recipient_list_after[0];
 
         test_env::set_caller(positions_owner);
-        let owner_new_position = invariant
-            .get_position(positions_owner, transferred_index)
-            .unwrap();
+        let owner_new_position = invariant.get_position(transferred_id).unwrap();
 
         assert_eq!(recipient_list_after.len(), recipient_list_before.len() - 1);
         assert_eq!(owner_list_before.len() + 1, owner_list_after.len());
+        assert_eq!(
+            invariant.get_position_ids(positions_owner),
+            vec![transferred_id]
+        );
 
         // move last position
         assert!(positions_equals(
@@ -701,7 +702,7 @@ fn test_transfer_position_ownership() {
         ));
 
         // Equals fields od transferred position
-        assert!(positions_equals(owner_new_position, removed_position));
+        assert_eq!(owner_new_position, removed_position);
     }
 }
 
@@ -822,11 +823,11 @@ fn test_only_owner_can_transfer_position() {
     }
     // Transfer first position
     {
-        let transferred_index = 0;
+        let transferred_id = 0;
         let unauthorized_user = test_env::get_account(1);
         test_env::set_caller(unauthorized_user);
-        let result = invariant.transfer_position(transferred_index, position_owner);
-        assert_eq!(result, Err(InvariantError::PositionNotFound));
+        let result = invariant.transfer_position(transferred_id, position_owner);
+        assert_eq!(result, Err(InvariantError::NotPositionOwner));
     }
 }
 
@@ -903,7 +904,7 @@ fn test_multiple_positions_on_same_tick() {
             )
             .unwrap();
 
-        let first_position = invariant.get_position(positions_owner, 0).unwrap();
+        let first_position = invariant.get_position(0).unwrap();
 
         invariant
             .create_position(
@@ -919,7 +920,7 @@ fn test_multiple_positions_on_same_tick() {
             )
             .unwrap();
 
-        let second_position = invariant.get_position(positions_owner, 1).unwrap();
+        let second_position = invariant.get_position(1).unwrap();
 
         invariant
             .create_position(
@@ -935,7 +936,7 @@ fn test_multiple_positions_on_same_tick() {
             )
             .unwrap();
 
-        let third_position = invariant.get_position(positions_owner, 2).unwrap();
+        let third_position = invariant.get_position(2).unwrap();
 
         assert!(first_position.lower_tick_index == second_position.lower_tick_index);
         assert!(first_position.upper_tick_index == second_position.upper_tick_index);
@@ -1040,7 +1041,7 @@ fn test_multiple_positions_on_same_tick() {
             )
             .unwrap();
 
-        let first_position = invariant.get_position(positions_owner, 3).unwrap();
+        let first_position = invariant.get_position(3).unwrap();
 
         // Check first position
         assert!(first_position.pool_key == pool_key);
@@ -1067,7 +1068,7 @@ fn test_multiple_positions_on_same_tick() {
             )
             .unwrap();
 
-        let second_position = invariant.get_position(positions_owner, 4).unwrap();
+        let second_position = invariant.get_position(4).unwrap();
 
         // Check second position
         assert!(second_position.pool_key == pool_key);
@@ -1093,7 +1094,7 @@ fn test_multiple_positions_on_same_tick() {
             )
             .unwrap();
 
-        let third_position = invariant.get_position(positions_owner, 5).unwrap();
+        let third_position = invariant.get_position(5).unwrap();
 
         // Check third position
         assert!(third_position.pool_key == pool_key);
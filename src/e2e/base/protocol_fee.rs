@@ -1,5 +1,5 @@
 use crate::contracts::InvariantError;
-use crate::contracts::PoolKey;
+use crate::contracts::{PoolKey, WithdrawProtocolFeeEvent};
 use crate::e2e::snippets::init;
 use crate::math::fee_growth::FeeGrowth;
 use crate::math::liquidity::Liquidity;
@@ -10,6 +10,7 @@ use crate::math::token_amount::TokenAmount;
 use crate::math::MIN_SQRT_PRICE;
 use crate::FeeTier;
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::{U128, U256};
 
@@ -194,6 +195,17 @@ fn test_protocol_fee() {
     assert_eq!(amount_x, U256::from(1499));
     assert_eq!(amount_y, U256::from(7));
 
+    assert_events!(
+        invariant,
+        WithdrawProtocolFeeEvent {
+            timestamp: 0,
+            address: deployer,
+            pool: pool_key,
+            amount_x: TokenAmount::new(U256::from(1)),
+            amount_y: TokenAmount::new(U256::from(0)),
+        }
+    );
+
     let pool_after_withdraw = invariant
         .get_pool(
             pool_key.token_x,
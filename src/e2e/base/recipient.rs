@@ -0,0 +1,147 @@
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::liquidity::Liquidity;
+use crate::math::sqrt_price::SqrtPrice;
+use crate::math::token_amount::TokenAmount;
+use crate::math::MIN_SQRT_PRICE;
+use alloc::vec;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_create_position_with_owner() {
+    let deployer = test_env::get_account(0);
+    let owner = test_env::get_account(1);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+
+    let balance_x_before = token_x.balance_of(&deployer);
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    let position = invariant
+        .create_position_with_owner(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+            owner,
+        )
+        .unwrap();
+
+    assert_eq!(invariant.get_position_owner(position.id).unwrap(), owner);
+    assert_eq!(invariant.get_position_ids(owner), vec![position.id]);
+    assert!(invariant.get_position_ids(deployer).is_empty());
+
+    assert!(token_x.balance_of(&deployer) < balance_x_before);
+    assert!(token_y.balance_of(&deployer) < balance_y_before);
+    assert_eq!(token_x.balance_of(&owner), U256::from(0));
+    assert_eq!(token_y.balance_of(&owner), U256::from(0));
+
+    test_env::set_caller(owner);
+    let (amount_x, amount_y) = invariant.remove_position(position.id).unwrap();
+    assert_eq!(token_x.balance_of(&owner), amount_x.get());
+    assert_eq!(token_y.balance_of(&owner), amount_y.get());
+}
+
+#[test]
+fn test_swap_with_recipient() {
+    let deployer = test_env::get_account(0);
+    let recipient = test_env::get_account(1);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let balance_x_before = token_x.balance_of(&deployer);
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    let result = invariant
+        .swap_with_recipient(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+            recipient,
+        )
+        .unwrap();
+
+    assert_eq!(result.amount_out, TokenAmount::new(U256::from(993)));
+    assert_eq!(
+        balance_x_before - token_x.balance_of(&deployer),
+        result.amount_in.get()
+    );
+    assert_eq!(token_y.balance_of(&deployer), balance_y_before);
+    assert_eq!(token_x.balance_of(&recipient), U256::from(0));
+    assert_eq!(token_y.balance_of(&recipient), result.amount_out.get());
+}
+
+#[test]
+fn test_swap_with_recipient_and_deadline() {
+    let deployer = test_env::get_account(0);
+    let recipient = test_env::get_account(1);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    test_env::advance_block_time_by(10);
+
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    let result = invariant
+        .swap_with_recipient_and_deadline(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+            recipient,
+            10,
+        )
+        .unwrap();
+
+    assert_eq!(token_y.balance_of(&deployer), balance_y_before);
+    assert_eq!(token_x.balance_of(&recipient), U256::from(0));
+    assert_eq!(token_y.balance_of(&recipient), result.amount_out.get());
+}
+
+#[test]
+#[should_panic]
+fn test_swap_with_recipient_after_deadline() {
+    let deployer = test_env::get_account(0);
+    let recipient = test_env::get_account(1);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    test_env::advance_block_time_by(10);
+
+    invariant
+        .swap_with_recipient_and_deadline(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(1000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+            recipient,
+            9,
+        )
+        .unwrap();
+}
@@ -1,8 +1,10 @@
 use crate::contracts::errors::InvariantError;
+use crate::contracts::RemoveFeeTierEvent;
 use crate::math::percentage::Percentage;
 use crate::FeeTier;
 use crate::InvariantDeployer;
 use decimal::*;
+use odra::assert_events;
 use odra::test_env;
 use odra::types::U128;
 
@@ -25,6 +27,15 @@ fn test_remove_fee_tier() {
         .unwrap();
     let exist = invariant.fee_tier_exist(fee_tier.fee.get(), fee_tier.tick_spacing);
     assert!(!exist);
+
+    assert_events!(
+        invariant,
+        RemoveFeeTierEvent {
+            timestamp: 0,
+            address: deployer,
+            fee_tier,
+        }
+    );
 }
 
 #[test]
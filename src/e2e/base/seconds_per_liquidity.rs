@@ -0,0 +1,156 @@
+use crate::contracts::{FeeTier, PoolKey};
+use crate::e2e::snippets::{init, init_basic_pool, init_basic_position};
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::seconds_per_liquidity::SecondsPerLiquidity;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::MIN_SQRT_PRICE;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_seconds_per_liquidity_inside() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    let in_range = invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    let out_of_range = invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            20,
+            40,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        invariant
+            .get_seconds_per_liquidity_inside(in_range.id)
+            .unwrap(),
+        SecondsPerLiquidity::new(U128::from(0))
+    );
+
+    // block time is in milliseconds, 100 seconds over 1000000 of liquidity
+    test_env::advance_block_time_by(100_000);
+
+    assert_eq!(
+        invariant
+            .get_seconds_per_liquidity_inside(in_range.id)
+            .unwrap(),
+        SecondsPerLiquidity::from_scale(1, 4)
+    );
+    assert_eq!(
+        invariant
+            .get_seconds_per_liquidity_inside(out_of_range.id)
+            .unwrap(),
+        SecondsPerLiquidity::new(U128::from(0))
+    );
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(
+        pool.seconds_per_liquidity_global,
+        SecondsPerLiquidity::new(U128::from(0))
+    );
+}
+
+#[test]
+fn test_remove_position_and_cross_tick_after_days_without_updates() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    let narrow = init_basic_position(&mut invariant, pool_key);
+    let wide = invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -100,
+            100,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    // 5 days in milliseconds
+    test_env::advance_block_time_by(5 * 24 * 60 * 60 * 1000);
+
+    invariant
+        .swap(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            true,
+            U256::from(5000),
+            true,
+            U128::from(MIN_SQRT_PRICE),
+        )
+        .unwrap();
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert!(pool.current_tick_index < -20);
+    assert!(pool.seconds_per_liquidity_global > SecondsPerLiquidity::new(U128::from(0)));
+
+    invariant.remove_position(narrow.id).unwrap();
+    invariant.remove_position(wide.id).unwrap();
+}
@@ -1,4 +1,4 @@
-use crate::contracts::PoolKey;
+use crate::contracts::{InvariantError, PoolKey};
 use crate::math::liquidity::Liquidity;
 use crate::math::percentage::Percentage;
 use crate::math::sqrt_price::calculate_sqrt_price;
@@ -150,9 +150,29 @@ fn test_swap_route() {
             },
         ];
 
-        let expected_token_amount = invariant
-            .quote_route(amount_in.get(), swaps.clone())
+        let quote_route_result = invariant
+            .quote_route_with_hops(amount_in.get(), swaps.clone())
             .unwrap();
+        let expected_token_amount = quote_route_result.amount_out;
+        assert_eq!(
+            invariant
+                .quote_route(amount_in.get(), swaps.clone())
+                .unwrap(),
+            expected_token_amount
+        );
+
+        assert_eq!(quote_route_result.amount_in, amount_in);
+        assert_eq!(quote_route_result.hops.len(), 2);
+        assert_eq!(quote_route_result.hops[0].amount_in, amount_in);
+        assert_eq!(
+            quote_route_result.hops[0].amount_out,
+            quote_route_result.hops[1].amount_in
+        );
+        assert_eq!(quote_route_result.hops[1].amount_out, expected_token_amount);
+        assert!(quote_route_result
+            .hops
+            .iter()
+            .all(|hop| !hop.fee.is_zero() && hop.ticks.is_empty()));
         invariant
             .swap_route(
                 amount_in.get(),
@@ -224,3 +244,687 @@ fn test_swap_route() {
         assert_eq!(deployer_z_after - deployer_z_before, U256::from(0));
     }
 }
+
+#[test]
+fn test_swap_route_with_recipient() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+    // Init basic dex and tokens
+    let mint_amount = Some(U256::from(10u128.pow(10)));
+    let fee = Percentage::from_scale(1, 2);
+    let mut invariant = InvariantDeployer::init(fee.get());
+    let token_0 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_1 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_2 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+
+    let mut token_vector = [token_0, token_1, token_2];
+    token_vector.sort_by(|a, b| a.address().cmp(b.address()));
+    let [mut token_x, mut token_y, mut token_z] = token_vector;
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 1).unwrap();
+    let pool_key_xy = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+    let pool_key_yz = PoolKey::new(*token_y.address(), *token_z.address(), fee_tier).unwrap();
+
+    // Add fee tier
+    {
+        invariant
+            .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+            .unwrap();
+
+        let exist = invariant.fee_tier_exist(fee_tier.fee.get(), fee_tier.tick_spacing);
+        assert!(exist);
+    }
+    // Init x to y pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Init y to z pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Open positions on both pools
+    {
+        let amount = U256::from(2u128.pow(127));
+        token_x.mint(&deployer, &amount);
+        token_y.mint(&deployer, &amount);
+        token_z.mint(&deployer, &amount);
+
+        token_x.approve(invariant.address(), &amount);
+        token_y.approve(invariant.address(), &amount);
+        token_z.approve(invariant.address(), &amount);
+
+        let liquidity_delta = Liquidity::new(U256::from(2u128.pow(63) - 1));
+        let lower_tick = -1;
+        let upper_tick = 1;
+        let pool = invariant
+            .get_pool(
+                *token_x.address(),
+                *token_y.address(),
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+
+        let slippage_limit = pool.sqrt_price;
+        invariant
+            .create_position(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+        invariant
+            .create_position(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+    }
+    // Perform swaps
+    {
+        let amount_in = TokenAmount::new(U256::from(1000));
+        let swapper = test_env::get_account(1);
+        token_x.mint(&swapper, &amount_in.get());
+
+        test_env::set_caller(swapper);
+        token_x.approve(invariant.address(), &amount_in.get());
+        token_y.approve(invariant.address(), &amount_in.get());
+
+        let slippage = Percentage::new(U128::from(0));
+        let swaps = vec![
+            SwapHop {
+                token_x: pool_key_xy.token_x,
+                token_y: pool_key_xy.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+            SwapHop {
+                token_x: pool_key_yz.token_x,
+                token_y: pool_key_yz.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+        ];
+
+        let expected_token_amount = invariant
+            .quote_route(amount_in.get(), swaps.clone())
+            .unwrap();
+
+        let recipient = test_env::get_account(2);
+        invariant
+            .swap_route_with_recipient(
+                amount_in.get(),
+                expected_token_amount.get(),
+                slippage.get(),
+                swaps,
+                recipient,
+            )
+            .unwrap();
+
+        // Check states
+        assert_eq!(token_x.balance_of(&swapper), U256::from(0));
+        assert_eq!(token_y.balance_of(&swapper), U256::from(0));
+        assert_eq!(token_z.balance_of(&swapper), U256::from(0));
+
+        assert_eq!(token_x.balance_of(&recipient), U256::from(0));
+        assert_eq!(token_y.balance_of(&recipient), U256::from(0));
+        assert_eq!(token_z.balance_of(&recipient), U256::from(986));
+    }
+}
+
+#[test]
+fn test_swap_route_exact_out() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+    // Init basic dex and tokens
+    let mint_amount = Some(U256::from(10u128.pow(10)));
+    let fee = Percentage::from_scale(1, 2);
+    let mut invariant = InvariantDeployer::init(fee.get());
+    let token_0 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_1 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_2 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+
+    let mut token_vector = [token_0, token_1, token_2];
+    token_vector.sort_by(|a, b| a.address().cmp(b.address()));
+    let [mut token_x, mut token_y, mut token_z] = token_vector;
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 1).unwrap();
+    let pool_key_xy = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+    let pool_key_yz = PoolKey::new(*token_y.address(), *token_z.address(), fee_tier).unwrap();
+
+    // Add fee tier
+    {
+        invariant
+            .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+            .unwrap();
+
+        let exist = invariant.fee_tier_exist(fee_tier.fee.get(), fee_tier.tick_spacing);
+        assert!(exist);
+    }
+    // Init x to y pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Init y to z pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Open positions on both pools
+    {
+        let amount = U256::from(2u128.pow(127));
+        token_x.mint(&deployer, &amount);
+        token_y.mint(&deployer, &amount);
+        token_z.mint(&deployer, &amount);
+
+        token_x.approve(invariant.address(), &amount);
+        token_y.approve(invariant.address(), &amount);
+        token_z.approve(invariant.address(), &amount);
+
+        let liquidity_delta = Liquidity::new(U256::from(2u128.pow(63) - 1));
+        let lower_tick = -1;
+        let upper_tick = 1;
+        let pool = invariant
+            .get_pool(
+                *token_x.address(),
+                *token_y.address(),
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+
+        let slippage_limit = pool.sqrt_price;
+        invariant
+            .create_position(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+        invariant
+            .create_position(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+    }
+    // Perform swaps
+    {
+        let amount_out = TokenAmount::new(U256::from(1000));
+        let swapper = test_env::get_account(1);
+
+        let swaps = vec![
+            SwapHop {
+                token_x: pool_key_xy.token_x,
+                token_y: pool_key_xy.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+            SwapHop {
+                token_x: pool_key_yz.token_x,
+                token_y: pool_key_yz.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+        ];
+
+        let expected_amount_in = invariant
+            .quote_route_exact_out(amount_out.get(), swaps.clone())
+            .unwrap()
+            .amount_in;
+        token_x.mint(&swapper, &expected_amount_in.get());
+
+        test_env::set_caller(swapper);
+        token_x.approve(invariant.address(), &expected_amount_in.get());
+        token_y.approve(invariant.address(), &expected_amount_in.get());
+
+        invariant
+            .swap_route_exact_out(amount_out.get(), expected_amount_in.get(), swaps)
+            .unwrap();
+
+        // Check states
+        let swapper_x = token_x.balance_of(&swapper);
+        let swapper_y = token_y.balance_of(&swapper);
+        let swapper_z = token_z.balance_of(&swapper);
+
+        assert_eq!(swapper_x, U256::from(0));
+        assert_eq!(swapper_y, U256::from(0));
+        assert_eq!(swapper_z, amount_out.get());
+        assert!(expected_amount_in > amount_out);
+    }
+}
+
+#[test]
+fn test_swap_route_exact_out_with_recipient() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+    // Init basic dex and tokens
+    let mint_amount = Some(U256::from(10u128.pow(10)));
+    let fee = Percentage::from_scale(1, 2);
+    let mut invariant = InvariantDeployer::init(fee.get());
+    let token_0 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_1 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_2 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+
+    let mut token_vector = [token_0, token_1, token_2];
+    token_vector.sort_by(|a, b| a.address().cmp(b.address()));
+    let [mut token_x, mut token_y, mut token_z] = token_vector;
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 1).unwrap();
+    let pool_key_xy = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+    let pool_key_yz = PoolKey::new(*token_y.address(), *token_z.address(), fee_tier).unwrap();
+
+    // Add fee tier
+    {
+        invariant
+            .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+            .unwrap();
+
+        let exist = invariant.fee_tier_exist(fee_tier.fee.get(), fee_tier.tick_spacing);
+        assert!(exist);
+    }
+    // Init x to y pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Init y to z pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Open positions on both pools
+    {
+        let amount = U256::from(2u128.pow(127));
+        token_x.mint(&deployer, &amount);
+        token_y.mint(&deployer, &amount);
+        token_z.mint(&deployer, &amount);
+
+        token_x.approve(invariant.address(), &amount);
+        token_y.approve(invariant.address(), &amount);
+        token_z.approve(invariant.address(), &amount);
+
+        let liquidity_delta = Liquidity::new(U256::from(2u128.pow(63) - 1));
+        let lower_tick = -1;
+        let upper_tick = 1;
+        let pool = invariant
+            .get_pool(
+                *token_x.address(),
+                *token_y.address(),
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+
+        let slippage_limit = pool.sqrt_price;
+        invariant
+            .create_position(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+        invariant
+            .create_position(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+    }
+    // Perform swaps
+    {
+        let amount_out = TokenAmount::new(U256::from(1000));
+        let swapper = test_env::get_account(1);
+
+        let swaps = vec![
+            SwapHop {
+                token_x: pool_key_xy.token_x,
+                token_y: pool_key_xy.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+            SwapHop {
+                token_x: pool_key_yz.token_x,
+                token_y: pool_key_yz.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+        ];
+
+        let expected_amount_in = invariant
+            .quote_route_exact_out(amount_out.get(), swaps.clone())
+            .unwrap()
+            .amount_in;
+        token_x.mint(&swapper, &expected_amount_in.get());
+
+        test_env::set_caller(swapper);
+        token_x.approve(invariant.address(), &expected_amount_in.get());
+        token_y.approve(invariant.address(), &expected_amount_in.get());
+
+        let recipient = test_env::get_account(2);
+        invariant
+            .swap_route_exact_out_with_recipient(
+                amount_out.get(),
+                expected_amount_in.get(),
+                swaps,
+                recipient,
+            )
+            .unwrap();
+
+        // Check states
+        assert_eq!(token_x.balance_of(&swapper), U256::from(0));
+        assert_eq!(token_y.balance_of(&swapper), U256::from(0));
+        assert_eq!(token_z.balance_of(&swapper), U256::from(0));
+
+        assert_eq!(token_x.balance_of(&recipient), U256::from(0));
+        assert_eq!(token_y.balance_of(&recipient), U256::from(0));
+        assert_eq!(token_z.balance_of(&recipient), amount_out.get());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_swap_route_exact_out_over_maximum_amount_in() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+    // Init basic dex and tokens
+    let mint_amount = Some(U256::from(10u128.pow(10)));
+    let fee = Percentage::from_scale(1, 2);
+    let mut invariant = InvariantDeployer::init(fee.get());
+    let token_0 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_1 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_2 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+
+    let mut token_vector = [token_0, token_1, token_2];
+    token_vector.sort_by(|a, b| a.address().cmp(b.address()));
+    let [mut token_x, mut token_y, mut token_z] = token_vector;
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 1).unwrap();
+    let pool_key_xy = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+    let pool_key_yz = PoolKey::new(*token_y.address(), *token_z.address(), fee_tier).unwrap();
+
+    // Add fee tier
+    {
+        invariant
+            .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+            .unwrap();
+
+        let exist = invariant.fee_tier_exist(fee_tier.fee.get(), fee_tier.tick_spacing);
+        assert!(exist);
+    }
+    // Init x to y pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Init y to z pool
+    {
+        let init_tick = 0;
+        let init_sqrt_price = calculate_sqrt_price(init_tick).unwrap();
+        invariant
+            .create_pool(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                init_sqrt_price.get(),
+                init_tick,
+            )
+            .unwrap();
+    }
+    // Open positions on both pools
+    {
+        let amount = U256::from(2u128.pow(127));
+        token_x.mint(&deployer, &amount);
+        token_y.mint(&deployer, &amount);
+        token_z.mint(&deployer, &amount);
+
+        token_x.approve(invariant.address(), &amount);
+        token_y.approve(invariant.address(), &amount);
+        token_z.approve(invariant.address(), &amount);
+
+        let liquidity_delta = Liquidity::new(U256::from(2u128.pow(63) - 1));
+        let lower_tick = -1;
+        let upper_tick = 1;
+        let pool = invariant
+            .get_pool(
+                *token_x.address(),
+                *token_y.address(),
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+            )
+            .unwrap();
+
+        let slippage_limit = pool.sqrt_price;
+        invariant
+            .create_position(
+                pool_key_xy.token_x,
+                pool_key_xy.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+        invariant
+            .create_position(
+                pool_key_yz.token_x,
+                pool_key_yz.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta.get(),
+                slippage_limit.get(),
+                slippage_limit.get(),
+            )
+            .unwrap();
+    }
+    // Perform swaps
+    {
+        let amount_out = TokenAmount::new(U256::from(1000));
+        let swapper = test_env::get_account(1);
+
+        let swaps = vec![
+            SwapHop {
+                token_x: pool_key_xy.token_x,
+                token_y: pool_key_xy.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+            SwapHop {
+                token_x: pool_key_yz.token_x,
+                token_y: pool_key_yz.token_y,
+                fee: fee_tier.fee.get(),
+                tick_spacing: fee_tier.tick_spacing,
+                x_to_y: true,
+            },
+        ];
+
+        let expected_amount_in = invariant
+            .quote_route_exact_out(amount_out.get(), swaps.clone())
+            .unwrap()
+            .amount_in;
+        token_x.mint(&swapper, &expected_amount_in.get());
+
+        test_env::set_caller(swapper);
+        token_x.approve(invariant.address(), &expected_amount_in.get());
+        token_y.approve(invariant.address(), &expected_amount_in.get());
+
+        let max_amount_in = expected_amount_in.get() - U256::from(1);
+        let result = invariant.swap_route_exact_out(amount_out.get(), max_amount_in, swaps);
+
+        assert_eq!(result, Err(InvariantError::AmountOverMaximumAmountIn));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_quote_route_exact_out_duplicated_pool() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+    let mint_amount = Some(U256::from(10u128.pow(10)));
+    let mut invariant = InvariantDeployer::init(Percentage::from_scale(1, 2).get());
+    let token_0 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+    let token_1 = Erc20Deployer::init(String::from(""), String::from(""), 0, &mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 1).unwrap();
+    let pool_key = PoolKey::new(*token_0.address(), *token_1.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    let swaps = vec![
+        SwapHop {
+            token_x: pool_key.token_x,
+            token_y: pool_key.token_y,
+            fee: fee_tier.fee.get(),
+            tick_spacing: fee_tier.tick_spacing,
+            x_to_y: true,
+        },
+        SwapHop {
+            token_x: pool_key.token_x,
+            token_y: pool_key.token_y,
+            fee: fee_tier.fee.get(),
+            tick_spacing: fee_tier.tick_spacing,
+            x_to_y: false,
+        },
+    ];
+
+    invariant
+        .quote_route_exact_out(U256::from(1000), swaps)
+        .unwrap();
+}
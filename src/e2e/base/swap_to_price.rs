@@ -0,0 +1,136 @@
+use crate::contracts::PoolKey;
+use crate::e2e::snippets::{init_basic_pool, init_basic_position};
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::{InvariantRef, QuoteResult};
+use decimal::Decimal;
+use odra::test_env;
+use odra::types::U256;
+
+fn quote_swap_to_price(
+    invariant: &InvariantRef,
+    pool_key: PoolKey,
+    target_sqrt_price: SqrtPrice,
+    max_amount_in: U256,
+) -> QuoteResult {
+    invariant
+        .quote_swap_to_price(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            target_sqrt_price.get(),
+            max_amount_in,
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_swap_to_lower_price() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, token_x, token_y, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let target_sqrt_price = calculate_sqrt_price(-10).unwrap();
+    let max_amount_in = U256::from(1000);
+
+    let quote = quote_swap_to_price(&invariant, pool_key, target_sqrt_price, max_amount_in);
+    assert!(quote.amount_in.get() < max_amount_in);
+    assert_eq!(quote.target_sqrt_price, target_sqrt_price);
+
+    let balance_x_before = token_x.balance_of(&deployer);
+    let balance_y_before = token_y.balance_of(&deployer);
+
+    let result = invariant
+        .swap_to_price(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            target_sqrt_price.get(),
+            max_amount_in,
+        )
+        .unwrap();
+
+    assert_eq!(result.amount_in, quote.amount_in);
+    assert_eq!(result.amount_out, quote.amount_out);
+    assert_eq!(
+        balance_x_before - token_x.balance_of(&deployer),
+        result.amount_in.get()
+    );
+    assert_eq!(
+        token_y.balance_of(&deployer) - balance_y_before,
+        result.amount_out.get()
+    );
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool.sqrt_price, target_sqrt_price);
+}
+
+#[test]
+fn test_swap_to_higher_price() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let target_sqrt_price = calculate_sqrt_price(5).unwrap();
+    let max_amount_in = U256::from(1000);
+
+    let quote = quote_swap_to_price(&invariant, pool_key, target_sqrt_price, max_amount_in);
+
+    let result = invariant
+        .swap_to_price(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            target_sqrt_price.get(),
+            max_amount_in,
+        )
+        .unwrap();
+    assert_eq!(result.amount_in, quote.amount_in);
+
+    let pool = invariant
+        .get_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+        )
+        .unwrap();
+    assert_eq!(pool.sqrt_price, target_sqrt_price);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_to_price_over_maximum_amount_in() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let (mut invariant, _, _, pool_key) = init_basic_pool();
+    init_basic_position(&mut invariant, pool_key);
+
+    let target_sqrt_price = calculate_sqrt_price(-10).unwrap();
+    let quote = quote_swap_to_price(&invariant, pool_key, target_sqrt_price, U256::from(1000));
+
+    invariant
+        .swap_to_price(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            target_sqrt_price.get(),
+            quote.amount_in.get() - 1,
+        )
+        .unwrap();
+}
@@ -0,0 +1,311 @@
+use crate::contracts::{FeeTier, PoolKey};
+use crate::e2e::snippets::init;
+use crate::math::liquidity::Liquidity;
+use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
+use crate::math::{CHUNK_SIZE, LIQUIDITY_TICK_LIMIT, MAX_TICK, TICKMAP_CHUNK_LIMIT};
+use alloc::vec;
+use decimal::{Decimal, Factories};
+use odra::test_env;
+use odra::types::{U128, U256};
+
+#[test]
+fn test_get_liquidity_ticks_and_tickmap() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    for (lower_tick, upper_tick) in [(-20, 10), (-1000, 2000)] {
+        invariant
+            .create_position(
+                pool_key.token_x,
+                pool_key.token_y,
+                fee_tier.fee.get(),
+                fee_tier.tick_spacing,
+                lower_tick,
+                upper_tick,
+                Liquidity::from_integer(1000000).get(),
+                SqrtPrice::new(U128::from(0)).get(),
+                SqrtPrice::max_instance().get(),
+            )
+            .unwrap();
+    }
+
+    let tickmap = invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -1000,
+            1000,
+            false,
+        )
+        .unwrap();
+    assert_eq!(tickmap, (vec![-1000, -20, 10], None));
+
+    let tickmap = invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -20,
+            2000,
+            true,
+        )
+        .unwrap();
+    assert_eq!(tickmap, (vec![2000, 10, -20], None));
+    let tickmap = tickmap.0;
+
+    let ticks = invariant
+        .get_liquidity_ticks(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            tickmap.clone(),
+        )
+        .unwrap();
+    assert_eq!(ticks.len(), 3);
+    for (tick, index) in ticks.iter().zip(tickmap) {
+        assert_eq!(
+            *tick,
+            invariant
+                .get_tick(
+                    pool_key.token_x,
+                    pool_key.token_y,
+                    fee_tier.fee.get(),
+                    fee_tier.tick_spacing,
+                    index,
+                )
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_get_liquidity_ticks_not_initialized() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, token_x, token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    invariant
+        .get_liquidity_ticks(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            vec![0],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_get_tickmap_invalid_range() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (invariant, token_x, token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            10,
+            -10,
+            false,
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_get_tickmap_over_chunk_limit() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 1).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    // ticks placed right past the chunks read by a single call in both directions
+    let span = TICKMAP_CHUNK_LIMIT as i32 * CHUNK_SIZE;
+    let lower_tick = -MAX_TICK + span;
+    let upper_tick = (2 * MAX_TICK / CHUNK_SIZE + 1) * CHUNK_SIZE - 1 - MAX_TICK - span;
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            lower_tick,
+            upper_tick,
+            Liquidity::from_integer(1000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap();
+
+    let (ticks, next_tick) = invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -MAX_TICK,
+            MAX_TICK,
+            false,
+        )
+        .unwrap();
+    assert!(ticks.is_empty());
+    assert_eq!(next_tick, Some(lower_tick));
+
+    let (ticks, _) = invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            next_tick.unwrap(),
+            MAX_TICK,
+            false,
+        )
+        .unwrap();
+    assert_eq!(ticks, vec![lower_tick]);
+
+    let (ticks, next_tick) = invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -MAX_TICK,
+            MAX_TICK,
+            true,
+        )
+        .unwrap();
+    assert!(ticks.is_empty());
+    assert_eq!(next_tick, Some(upper_tick));
+
+    let (ticks, _) = invariant
+        .get_tickmap(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            -MAX_TICK,
+            next_tick.unwrap(),
+            true,
+        )
+        .unwrap();
+    assert_eq!(ticks, vec![upper_tick]);
+}
+
+#[test]
+#[should_panic]
+fn test_get_liquidity_ticks_over_limit() {
+    let deployer = test_env::get_account(0);
+    test_env::set_caller(deployer);
+
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, token_x, token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    invariant
+        .get_liquidity_ticks(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            vec![0; LIQUIDITY_TICK_LIMIT as usize + 1],
+        )
+        .unwrap();
+}
@@ -1,9 +1,11 @@
-use crate::contracts::Position;
+use crate::contracts::{FeeTier, PoolKey, Position};
+use crate::math::liquidity::Liquidity;
 use crate::math::percentage::Percentage;
+use crate::math::sqrt_price::{calculate_sqrt_price, SqrtPrice};
 use crate::{Erc20Deployer, Erc20Ref, InvariantDeployer, InvariantRef};
 use alloc::string::String;
 use decimal::*;
-use odra::types::U256;
+use odra::types::{U128, U256};
 
 pub fn init(fee: Percentage, supply: U256) -> (InvariantRef, Erc20Ref, Erc20Ref) {
     let invariant = InvariantDeployer::init(fee.get());
@@ -16,6 +18,50 @@ pub fn init(fee: Percentage, supply: U256) -> (InvariantRef, Erc20Ref, Erc20Ref)
     }
 }
 
+pub fn init_basic_pool() -> (InvariantRef, Erc20Ref, Erc20Ref, PoolKey) {
+    let mint_amount = U256::from(10u128.pow(10));
+    let (mut invariant, mut token_x, mut token_y) = init(Percentage::from_scale(1, 2), mint_amount);
+
+    let fee_tier = FeeTier::new(Percentage::from_scale(6, 3), 10).unwrap();
+    let pool_key = PoolKey::new(*token_x.address(), *token_y.address(), fee_tier).unwrap();
+
+    invariant
+        .add_fee_tier(fee_tier.fee.get(), fee_tier.tick_spacing)
+        .unwrap();
+
+    invariant
+        .create_pool(
+            pool_key.token_x,
+            pool_key.token_y,
+            fee_tier.fee.get(),
+            fee_tier.tick_spacing,
+            calculate_sqrt_price(0).unwrap().get(),
+            0,
+        )
+        .unwrap();
+
+    token_x.approve(invariant.address(), &mint_amount);
+    token_y.approve(invariant.address(), &mint_amount);
+
+    (invariant, token_x, token_y, pool_key)
+}
+
+pub fn init_basic_position(invariant: &mut InvariantRef, pool_key: PoolKey) -> Position {
+    invariant
+        .create_position(
+            pool_key.token_x,
+            pool_key.token_y,
+            pool_key.fee_tier.fee.get(),
+            pool_key.fee_tier.tick_spacing,
+            -20,
+            10,
+            Liquidity::from_integer(1000000).get(),
+            SqrtPrice::new(U128::from(0)).get(),
+            SqrtPrice::max_instance().get(),
+        )
+        .unwrap()
+}
+
 pub fn positions_equals(position_a: Position, position_b: Position) -> bool {
     let mut equal = true;
 
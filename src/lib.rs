@@ -12,15 +12,25 @@ pub use odra_modules::erc20::{Erc20, Erc20Deployer, Erc20Ref};
 pub mod e2e;
 
 use crate::contracts::errors::InvariantError;
-use crate::math::{check_tick, percentage::Percentage, sqrt_price::SqrtPrice};
+use crate::math::{
+    check_tick, check_ticks,
+    percentage::Percentage,
+    sqrt_price::{calculate_sqrt_price, SqrtPrice},
+    LIQUIDITY_TICK_LIMIT,
+};
 use contracts::{events::*, unwrap_invariant_result, InvariantConfig, InvariantErrorReturn};
 use contracts::{
-    FeeTier, FeeTiers, Pool, PoolKey, PoolKeys, Pools, Position, Positions, Tick, Tickmap, Ticks,
-    UpdatePoolTick,
+    get_max_liquidity, Call, CallResult, FeeTier, FeeTiers, FlashCallbackRef, Incentive,
+    Incentives, LimitOrder, LimitOrderEpoch, LimitOrders, Oracles, PauseKind, PauseStatus, Pool,
+    PoolKey, PoolKeys, Pools, Position, Positions, Role, Stake, SwapCallbackRef, Tick, Tickmap,
+    Ticks, UpdatePoolTick,
 };
 use decimal::*;
-use math::clamm::{calculate_min_amount_out, compute_swap_step, SwapResult};
+use math::clamm::{
+    calculate_max_amount_in, calculate_min_amount_out, compute_swap_step, SwapResult,
+};
 use math::liquidity::Liquidity;
+use math::seconds_per_liquidity::{calculate_seconds_per_liquidity_inside, SecondsPerLiquidity};
 use math::token_amount::TokenAmount;
 use math::{MAX_SQRT_PRICE, MIN_SQRT_PRICE};
 use odra::contract_env;
@@ -28,7 +38,7 @@ use odra::prelude::vec;
 use odra::prelude::vec::Vec;
 use odra::types::event::OdraEvent;
 use odra::types::{Address, U128, U256};
-use odra::{OdraType, UnwrapOrRevert, Variable};
+use odra::{Mapping, OdraType, UnwrapOrRevert, Variable};
 use traceable_result::*;
 #[derive(OdraType, Debug, PartialEq)]
 pub struct QuoteResult {
@@ -47,6 +57,27 @@ pub struct CalculateSwapResult {
     pub pool: Pool,
     pub ticks: Vec<Tick>,
 }
+#[derive(OdraType, Debug, PartialEq)]
+pub struct SwapHopResult {
+    pub amount_in: TokenAmount,
+    pub amount_out: TokenAmount,
+    pub fee: TokenAmount,
+    pub start_sqrt_price: SqrtPrice,
+    pub target_sqrt_price: SqrtPrice,
+    pub ticks: Vec<Tick>,
+}
+#[derive(OdraType, Debug, PartialEq)]
+pub struct QuoteRouteResult {
+    pub amount_in: TokenAmount,
+    pub amount_out: TokenAmount,
+    pub hops: Vec<SwapHopResult>,
+}
+#[derive(OdraType, Debug, PartialEq)]
+pub struct ObserveResult {
+    pub tick_cumulatives: Vec<i64>,
+    pub average_ticks: Vec<i32>,
+    pub average_sqrt_prices: Vec<SqrtPrice>,
+}
 #[derive(OdraType, Debug)]
 pub struct SwapHop {
     pub token_x: Address,
@@ -56,15 +87,55 @@ pub struct SwapHop {
     pub x_to_y: bool,
 }
 
-#[odra::module]
+impl From<CalculateSwapResult> for SwapHopResult {
+    fn from(result: CalculateSwapResult) -> Self {
+        Self {
+            amount_in: result.amount_in,
+            amount_out: result.amount_out,
+            fee: result.fee,
+            start_sqrt_price: result.start_sqrt_price,
+            target_sqrt_price: result.target_sqrt_price,
+            ticks: result.ticks,
+        }
+    }
+}
+
+#[odra::module(events = [
+    CreatePositionEvent,
+    RemovePositionEvent,
+    CrossTickEvent,
+    SwapEvent,
+    FlashEvent,
+    AdminProposedEvent,
+    AdminChangedEvent,
+    RoleGrantedEvent,
+    RoleRevokedEvent,
+    CreatePoolEvent,
+    ClaimFeeEvent,
+    TransferPositionEvent,
+    WithdrawProtocolFeeEvent,
+    AddFeeTierEvent,
+    RemoveFeeTierEvent,
+    ChangeProtocolFeeEvent,
+    ChangeFeeReceiverEvent,
+    ChangePauseStatusEvent,
+    ChangePoolPauseStatusEvent
+])]
 pub struct Invariant {
     positions: Positions,
     pools: Pools,
     tickmap: Tickmap,
     ticks: Ticks,
-    fee_tiers: Variable<FeeTiers>,
-    pool_keys: Variable<PoolKeys>,
+    oracles: Oracles,
+    incentives: Incentives,
+    limit_orders: LimitOrders,
+    fee_tiers: FeeTiers,
+    pool_keys: PoolKeys,
     config: Variable<InvariantConfig>,
+    roles: Mapping<(Role, Address), bool>,
+    pool_pause_statuses: Mapping<PoolKey, PauseStatus>,
+    pool_protocol_fees: Mapping<PoolKey, Option<Percentage>>,
+    pool_reserves: Mapping<PoolKey, (TokenAmount, TokenAmount)>,
 }
 
 impl Invariant {
@@ -78,7 +149,7 @@ impl Invariant {
 
         let pool = unwrap_invariant_result(self.pools.get(pool_key));
 
-        let tick = Tick::create(index, &pool, current_timestamp);
+        let tick = unwrap!(Tick::create(index, &pool, current_timestamp));
         unwrap_invariant_result(self.ticks.add(pool_key, index, &tick));
 
         self.tickmap
@@ -105,9 +176,10 @@ impl Invariant {
         amount: TokenAmount,
         by_amount_in: bool,
         sqrt_price_limit: SqrtPrice,
+        allow_partial_fill: bool,
     ) -> Result<CalculateSwapResult, InvariantError> {
         let current_timestamp = contract_env::get_block_time();
-        let config = self.config.get().unwrap_or_revert();
+        let protocol_fee = self.get_effective_protocol_fee(pool_key);
         if amount.is_zero() {
             contract_env::revert(InvariantErrorReturn::AmountIsZero);
         }
@@ -167,7 +239,7 @@ impl Invariant {
                 remaining_amount -= result.amount_out;
             }
 
-            unwrap!(pool.add_fee(result.fee_amount, x_to_y, config.protocol_fee));
+            unwrap!(pool.add_fee(result.fee_amount, x_to_y, protocol_fee));
             event_fee_amount += result.fee_amount;
 
             pool.sqrt_price = result.next_sqrt_price;
@@ -175,9 +247,14 @@ impl Invariant {
             total_amount_in += result.amount_in + result.fee_amount;
             total_amount_out += result.amount_out;
 
-            // Fail if price would go over swap limit
+            // Fail if price would go over swap limit, unless the swap may stop at it
             if pool.sqrt_price == sqrt_price_limit && !remaining_amount.is_zero() {
-                contract_env::revert(InvariantErrorReturn::PriceLimitReached);
+                if !allow_partial_fill {
+                    contract_env::revert(InvariantErrorReturn::PriceLimitReached);
+                }
+
+                // leave the unused amount with the caller
+                remaining_amount = TokenAmount::new(U256::from(0));
             }
 
             let mut tick_update = {
@@ -201,7 +278,7 @@ impl Invariant {
                 by_amount_in,
                 x_to_y,
                 current_timestamp,
-                config.protocol_fee,
+                protocol_fee,
                 pool_key.fee_tier,
             );
 
@@ -239,384 +316,614 @@ impl Invariant {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn execute_swap(
+        &mut self,
+        caller: Address,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+        allow_partial_fill: bool,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        let current_timestamp = contract_env::get_block_time();
+
+        self.check_paused(pool_key, PauseKind::Swaps)?;
+
+        let pool = self.pools.get(pool_key)?;
+        self.oracles
+            .write(pool_key, current_timestamp, pool.current_tick_index)?;
+
+        let calculate_swap_result = self.calculate_swap(
+            pool_key,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            allow_partial_fill,
+        )?;
+
+        for tick in calculate_swap_result.ticks.iter() {
+            self.ticks.update(pool_key, tick.index, tick)?;
+            self.emit_cross_tick_event(caller, pool_key, tick);
+        }
+
+        self.pools.update(pool_key, &calculate_swap_result.pool)?;
+
+        self.settle_limit_orders(pool_key, x_to_y, &calculate_swap_result.ticks)?;
+
+        Ok(calculate_swap_result)
+    }
+
+    fn quote_swap(
+        &self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+        allow_partial_fill: bool,
+    ) -> Result<QuoteResult, InvariantError> {
+        let calculate_swap_result = unwrap_invariant_result(self.calculate_swap(
+            pool_key,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            allow_partial_fill,
+        ));
+
+        Ok(QuoteResult {
+            amount_in: calculate_swap_result.amount_in,
+            amount_out: calculate_swap_result.amount_out,
+            target_sqrt_price: calculate_swap_result.pool.sqrt_price,
+            ticks: calculate_swap_result.ticks,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn swap_and_transfer(
+        &mut self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount: TokenAmount,
+        by_amount_in: bool,
+        sqrt_price_limit: SqrtPrice,
+        allow_partial_fill: bool,
+        recipient: Address,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        let calculate_swap_result = unwrap_invariant_result(self.execute_swap(
+            caller,
+            pool_key,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            allow_partial_fill,
+        ));
+
+        if x_to_y {
+            Erc20Ref::at(&pool_key.token_x).transfer_from(
+                &caller,
+                &contract,
+                &calculate_swap_result.amount_in.get(),
+            );
+            Erc20Ref::at(&pool_key.token_y)
+                .transfer(&recipient, &calculate_swap_result.amount_out.get());
+        } else {
+            Erc20Ref::at(&pool_key.token_y).transfer_from(
+                &caller,
+                &contract,
+                &calculate_swap_result.amount_in.get(),
+            );
+            Erc20Ref::at(&pool_key.token_x)
+                .transfer(&recipient, &calculate_swap_result.amount_out.get());
+        };
+
+        self.swap_reserves(
+            pool_key,
+            x_to_y,
+            calculate_swap_result.amount_in,
+            calculate_swap_result.amount_out,
+        );
+
+        self.emit_swap_event(
+            caller,
+            pool_key,
+            &calculate_swap_result,
+            x_to_y,
+            by_amount_in,
+        );
+
+        Ok(calculate_swap_result)
+    }
+
+    fn execute_call(&mut self, call: Call) -> Result<CallResult, InvariantError> {
+        match call {
+            Call::Swap(
+                token_0,
+                token_1,
+                fee,
+                tick_spacing,
+                x_to_y,
+                amount,
+                by_amount_in,
+                sqrt_price_limit,
+            ) => self
+                .swap(
+                    token_0,
+                    token_1,
+                    fee,
+                    tick_spacing,
+                    x_to_y,
+                    amount,
+                    by_amount_in,
+                    sqrt_price_limit,
+                )
+                .map(CallResult::Swap),
+            Call::SwapRoute(amount_in, expected_amount_out, slippage, swaps) => self
+                .swap_route(amount_in, expected_amount_out, slippage, swaps)
+                .map(|_| CallResult::SwapRoute),
+            Call::CreatePosition(
+                token_0,
+                token_1,
+                fee,
+                tick_spacing,
+                lower_tick,
+                upper_tick,
+                liquidity_delta,
+                slippage_limit_lower,
+                slippage_limit_upper,
+            ) => self
+                .create_position(
+                    token_0,
+                    token_1,
+                    fee,
+                    tick_spacing,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                )
+                .map(CallResult::CreatePosition),
+            Call::IncreaseLiquidity(
+                id,
+                liquidity_delta,
+                slippage_limit_lower,
+                slippage_limit_upper,
+            ) => self
+                .increase_liquidity(
+                    id,
+                    liquidity_delta,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                )
+                .map(CallResult::IncreaseLiquidity),
+            Call::DecreaseLiquidity(
+                id,
+                liquidity_delta,
+                slippage_limit_lower,
+                slippage_limit_upper,
+            ) => self
+                .decrease_liquidity(
+                    id,
+                    liquidity_delta,
+                    slippage_limit_lower,
+                    slippage_limit_upper,
+                )
+                .map(|(x, y)| CallResult::DecreaseLiquidity(x, y)),
+            Call::RemovePosition(id) => self
+                .remove_position(id)
+                .map(|(x, y)| CallResult::RemovePosition(x, y)),
+            Call::ClaimFee(id) => self.claim_fee(id).map(|(x, y)| CallResult::ClaimFee(x, y)),
+            Call::TransferPosition(id, receiver) => self
+                .transfer_position(id, receiver)
+                .map(|_| CallResult::TransferPosition),
+        }
+    }
+
     fn route(
         &mut self,
         is_swap: bool,
         amount_in: TokenAmount,
         swaps: Vec<SwapHop>,
-    ) -> Result<TokenAmount, InvariantError> {
+        recipient: Address,
+    ) -> Result<QuoteRouteResult, InvariantError> {
+        let caller = contract_env::caller();
+        let mut hops: Vec<SwapHopResult> = vec![];
         let mut next_swap_amount = amount_in;
 
-        for swap in swaps.iter() {
-            let SwapHop {
-                token_x,
-                token_y,
-                fee,
-                tick_spacing,
-                x_to_y,
-            } = *swap;
-
-            let pool_key = unwrap_invariant_result(PoolKey::new(
-                token_x,
-                token_y,
-                unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
-            ));
+        for (index, swap) in swaps.iter().enumerate() {
+            let (pool_key, x_to_y, sqrt_price_limit) = Self::decode_swap_hop(swap);
 
-            let sqrt_price_limit = if x_to_y {
-                SqrtPrice::new(U128::from(MIN_SQRT_PRICE))
+            // intermediate outputs stay with the caller, who pays for the next hop
+            let hop_recipient = if index == swaps.len() - 1 {
+                recipient
             } else {
-                SqrtPrice::new(U128::from(MAX_SQRT_PRICE))
+                caller
             };
 
             let result = unwrap_invariant_result(if is_swap {
-                self.swap(
-                    pool_key.token_x,
-                    pool_key.token_y,
-                    pool_key.fee_tier.fee.get(),
-                    pool_key.fee_tier.tick_spacing,
+                self.swap_and_transfer(
+                    pool_key,
                     x_to_y,
-                    next_swap_amount.get(),
+                    next_swap_amount,
                     true,
-                    sqrt_price_limit.get(),
+                    sqrt_price_limit,
+                    false,
+                    hop_recipient,
                 )
             } else {
-                self.calculate_swap(pool_key, x_to_y, next_swap_amount, true, sqrt_price_limit)
+                self.calculate_swap(
+                    pool_key,
+                    x_to_y,
+                    next_swap_amount,
+                    true,
+                    sqrt_price_limit,
+                    false,
+                )
             });
 
             next_swap_amount = result.amount_out;
+            hops.push(SwapHopResult::from(result));
         }
 
-        Ok(next_swap_amount)
-    }
-
-    fn emit_create_position_event(
-        &self,
-        address: Address,
-        pool: PoolKey,
-        liquidity: Liquidity,
-        lower_tick: i32,
-        upper_tick: i32,
-        current_sqrt_price: SqrtPrice,
-    ) {
-        let timestamp = contract_env::get_block_time();
-        CreatePositionEvent {
-            timestamp,
-            address,
-            pool,
-            liquidity,
-            lower_tick,
-            upper_tick,
-            current_sqrt_price,
-        }
-        .emit();
+        Ok(QuoteRouteResult {
+            amount_in,
+            amount_out: next_swap_amount,
+            hops,
+        })
     }
 
-    fn emit_remove_position_event(
-        &self,
-        address: Address,
-        pool: PoolKey,
-        liquidity: Liquidity,
-        lower_tick: i32,
-        upper_tick: i32,
-        current_sqrt_price: SqrtPrice,
-    ) {
-        let timestamp = contract_env::get_block_time();
-        RemovePositionEvent {
-            timestamp,
-            address,
-            pool,
-            liquidity,
-            lower_tick,
-            upper_tick,
-            current_sqrt_price,
-        }
-        .emit();
-    }
+    fn route_exact_out(
+        &mut self,
+        is_swap: bool,
+        amount_out: TokenAmount,
+        max_amount_in: TokenAmount,
+        swaps: Vec<SwapHop>,
+        recipient: Address,
+    ) -> Result<QuoteRouteResult, InvariantError> {
+        let caller = contract_env::caller();
+        let mut hops: Vec<SwapHopResult> = vec![];
+        let mut next_swap_amount = amount_out;
 
-    fn emit_cross_tick_event(&self, address: Address, pool: PoolKey, indexes: Vec<i32>) {
-        let timestamp = contract_env::get_block_time();
-        CrossTickEvent {
-            timestamp,
-            address,
-            pool,
-            indexes,
-        }
-        .emit();
-    }
+        // every hop is simulated against the state from before the route, so a pool may appear only once
+        let mut pool_keys: Vec<PoolKey> = vec![];
+        for swap in swaps.iter() {
+            let (pool_key, _, _) = Self::decode_swap_hop(swap);
 
-    #[allow(clippy::too_many_arguments)]
-    fn emit_swap_event(
-        &self,
-        address: Address,
-        pool: PoolKey,
-        amount_in: TokenAmount,
-        amount_out: TokenAmount,
-        fee: TokenAmount,
-        start_sqrt_price: SqrtPrice,
-        target_sqrt_price: SqrtPrice,
-        x_to_y: bool,
-    ) {
-        let timestamp = contract_env::get_block_time();
-        SwapEvent {
-            timestamp,
-            address,
-            pool,
-            amount_in,
-            amount_out,
-            fee,
-            start_sqrt_price,
-            target_sqrt_price,
-            x_to_y,
+            if pool_keys.contains(&pool_key) {
+                contract_env::revert(InvariantErrorReturn::DuplicatedPoolInRoute);
+            }
+            pool_keys.push(pool_key);
         }
-        .emit()
-    }
-}
 
-#[odra::module]
-impl Entrypoints for Invariant {
-    #[odra(init)]
-    pub fn init(&mut self, fee: U128) {
-        let protocol_fee = Percentage::new(fee);
-        let caller = contract_env::caller();
+        // walk backwards to find the input required by every hop
+        for swap in swaps.iter().rev() {
+            let (pool_key, x_to_y, sqrt_price_limit) = Self::decode_swap_hop(swap);
 
-        self.pool_keys.set(PoolKeys::default());
-        self.fee_tiers.set(FeeTiers::default());
+            let result = unwrap_invariant_result(self.calculate_swap(
+                pool_key,
+                x_to_y,
+                next_swap_amount,
+                false,
+                sqrt_price_limit,
+                false,
+            ));
 
-        self.config.set(InvariantConfig {
-            admin: caller,
-            protocol_fee,
-        });
-    }
+            next_swap_amount = result.amount_in;
+            hops.push(SwapHopResult::from(result));
+        }
 
-    pub fn add_fee_tier(&mut self, fee: U128, tick_spacing: u32) -> Result<(), InvariantError> {
-        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
+        hops.reverse();
 
-        let caller = contract_env::caller();
-        let config = self.config.get().unwrap_or_revert();
-        let mut fee_tiers = self.fee_tiers.get().unwrap_or_revert();
+        // fail before any of the swaps is executed
+        if next_swap_amount > max_amount_in {
+            contract_env::revert(InvariantErrorReturn::AmountOverMaximumAmountIn);
+        }
 
-        if caller != config.admin {
-            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        if !is_swap {
+            return Ok(QuoteRouteResult {
+                amount_in: next_swap_amount,
+                amount_out,
+                hops,
+            });
         }
 
-        unwrap_invariant_result(fee_tiers.add(fee_tier));
+        let hop_amounts_out: Vec<TokenAmount> = hops.iter().map(|hop| hop.amount_out).collect();
+        hops.clear();
 
-        self.fee_tiers.set(fee_tiers);
-        Ok(())
-    }
+        for (index, (swap, hop_amount_out)) in swaps.iter().zip(hop_amounts_out).enumerate() {
+            let (pool_key, x_to_y, sqrt_price_limit) = Self::decode_swap_hop(swap);
 
-    pub fn fee_tier_exist(&self, fee: U128, tick_spacing: u32) -> bool {
-        let fee_tier = FeeTier::new(Percentage::new(fee), tick_spacing).unwrap();
-        let fee_tiers = self.fee_tiers.get().unwrap_or_revert();
-        fee_tiers.contains(fee_tier)
-    }
+            // intermediate outputs stay with the caller, who pays for the next hop
+            let hop_recipient = if index == swaps.len() - 1 {
+                recipient
+            } else {
+                caller
+            };
 
-    pub fn remove_fee_tier(&mut self, fee: U128, tick_spacing: u32) -> Result<(), InvariantError> {
-        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
-        let caller = contract_env::caller();
-        let config = self.config.get().unwrap_or_revert();
-        let mut fee_tiers = self.fee_tiers.get().unwrap_or_revert();
+            let result = unwrap_invariant_result(self.swap_and_transfer(
+                pool_key,
+                x_to_y,
+                hop_amount_out,
+                false,
+                sqrt_price_limit,
+                false,
+                hop_recipient,
+            ));
 
-        if caller != config.admin {
-            contract_env::revert(InvariantErrorReturn::NotAdmin);
+            hops.push(SwapHopResult::from(result));
         }
 
-        unwrap_invariant_result(fee_tiers.remove(fee_tier));
-
-        self.fee_tiers.set(fee_tiers);
-
-        Ok(())
-    }
-
-    pub fn get_fee_tiers(&self) -> Vec<FeeTier> {
-        let fee_tiers = self.fee_tiers.get().unwrap_or_revert();
-        fee_tiers.get_all()
+        Ok(QuoteRouteResult {
+            amount_in: hops.first().map_or(next_swap_amount, |hop| hop.amount_in),
+            amount_out,
+            hops,
+        })
     }
 
-    pub fn create_pool(
-        &mut self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
-        init_sqrt_price: U128,
-        init_tick: i32,
-    ) -> Result<(), InvariantError> {
-        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
-        let init_sqrt_price = SqrtPrice::new(init_sqrt_price);
+    fn decode_swap_hop(swap: &SwapHop) -> (PoolKey, bool, SqrtPrice) {
+        let SwapHop {
+            token_x,
+            token_y,
+            fee,
+            tick_spacing,
+            x_to_y,
+        } = *swap;
 
-        let current_timestamp = odra::contract_env::get_block_time();
-        let mut pool_keys = self.pool_keys.get().unwrap_or_revert();
-        let fee_tiers = self.fee_tiers.get().unwrap_or_revert();
-        let config = self.config.get().unwrap_or_revert();
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_x,
+            token_y,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
 
-        if !fee_tiers.contains(fee_tier) {
-            contract_env::revert(InvariantErrorReturn::FeeTierNotFound);
+        let sqrt_price_limit = if x_to_y {
+            SqrtPrice::new(U128::from(MIN_SQRT_PRICE))
+        } else {
+            SqrtPrice::new(U128::from(MAX_SQRT_PRICE))
         };
 
-        unwrap_invariant_result(
-            check_tick(init_tick, fee_tier.tick_spacing)
-                .map_err(|_| InvariantError::InvalidInitTick),
-        );
+        (pool_key, x_to_y, sqrt_price_limit)
+    }
 
-        let pool_key = unwrap_invariant_result(PoolKey::new(token_0, token_1, fee_tier));
+    // tokens deposited to the pool and not yet paid out, kept apart from other pools sharing the tokens
+    fn get_reserves(&self, pool_key: PoolKey) -> (TokenAmount, TokenAmount) {
+        self.pool_reserves.get(&pool_key).unwrap_or_default()
+    }
 
-        if self.pools.get(pool_key).is_ok() {
-            contract_env::revert(InvariantErrorReturn::PoolAlreadyExist);
-        };
+    fn increase_reserves(&mut self, pool_key: PoolKey, x: TokenAmount, y: TokenAmount) {
+        let (reserve_x, reserve_y) = self.get_reserves(pool_key);
 
-        let pool = unwrap_invariant_result(Pool::create(
-            init_sqrt_price,
-            init_tick,
-            current_timestamp,
-            fee_tier.tick_spacing,
-            config.admin,
-        ));
+        self.pool_reserves
+            .set(&pool_key, (reserve_x + x, reserve_y + y));
+    }
 
-        unwrap_invariant_result(self.pools.add(pool_key, &pool));
-        unwrap_invariant_result(pool_keys.add(pool_key));
+    fn decrease_reserves(&mut self, pool_key: PoolKey, x: TokenAmount, y: TokenAmount) {
+        let (reserve_x, reserve_y) = self.get_reserves(pool_key);
 
-        self.pool_keys.set(pool_keys);
-        Ok(())
+        self.pool_reserves.set(
+            &pool_key,
+            (
+                Self::checked_reserve_sub(reserve_x, x),
+                Self::checked_reserve_sub(reserve_y, y),
+            ),
+        );
     }
 
-    pub fn get_pool(
-        &self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
-    ) -> Result<Pool, InvariantError> {
-        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
-        let key: PoolKey = unwrap_invariant_result(PoolKey::new(token_0, token_1, fee_tier));
-        let pool = unwrap_invariant_result(self.pools.get(key));
+    fn swap_reserves(
+        &mut self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        amount_in: TokenAmount,
+        amount_out: TokenAmount,
+    ) {
+        let (reserve_x, reserve_y) = self.get_reserves(pool_key);
 
-        Ok(pool)
+        let reserves = if x_to_y {
+            (
+                reserve_x + amount_in,
+                Self::checked_reserve_sub(reserve_y, amount_out),
+            )
+        } else {
+            (
+                Self::checked_reserve_sub(reserve_x, amount_out),
+                reserve_y + amount_in,
+            )
+        };
+        self.pool_reserves.set(&pool_key, reserves);
     }
 
-    pub fn get_pools(&self) -> Vec<PoolKey> {
-        self.pool_keys.get().unwrap_or_revert().get_all()
+    // a pool can never pay out more than was deposited to it
+    fn checked_reserve_sub(reserve: TokenAmount, amount: TokenAmount) -> TokenAmount {
+        reserve
+            .checked_sub(amount)
+            .unwrap_or_else(|_| contract_env::revert(InvariantErrorReturn::InsufficientLiquidity))
     }
 
-    pub fn get_protocol_fee(&self) -> Percentage {
-        let config = self.config.get().unwrap_or_revert();
-        config.protocol_fee
+    fn get_effective_protocol_fee(&self, pool_key: PoolKey) -> Percentage {
+        self.pool_protocol_fees
+            .get(&pool_key)
+            .flatten()
+            .unwrap_or_else(|| self.config.get().unwrap_or_revert().protocol_fee)
     }
 
-    pub fn withdraw_protocol_fee(
+    fn set_pool_protocol_fee_override(
         &mut self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
+        pool_key: PoolKey,
+        protocol_fee: Option<Percentage>,
     ) -> Result<(), InvariantError> {
-        let pool_key = unwrap_invariant_result(PoolKey::new(
-            token_0,
-            token_1,
-            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
-        ));
-
         let caller = contract_env::caller();
-        let mut pool = unwrap_invariant_result(self.pools.get(pool_key));
 
-        if caller != pool.fee_receiver {
-            contract_env::revert(InvariantErrorReturn::NotFeeReceiver);
+        if !self.has_permission(caller, Role::ProtocolFeeManager) {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
         }
 
-        let (fee_protocol_token_x, fee_protocol_token_y) = pool.withdraw_protocol_fee(pool_key);
-
-        Erc20Ref::at(&pool_key.token_x).transfer(&pool.fee_receiver, &fee_protocol_token_x.get());
-        Erc20Ref::at(&pool_key.token_y).transfer(&pool.fee_receiver, &fee_protocol_token_y.get());
+        unwrap_invariant_result(self.pools.get(pool_key));
 
-        unwrap_invariant_result(self.pools.update(pool_key, &pool));
+        self.pool_protocol_fees.set(&pool_key, protocol_fee);
 
         Ok(())
     }
 
-    pub fn change_protocol_fee(&mut self, protocol_fee: U128) -> Result<(), InvariantError> {
-        let protocol_fee = Percentage::new(protocol_fee);
+    fn has_permission(&self, account: Address, role: Role) -> bool {
+        let config = self.config.get().unwrap_or_revert();
+
+        account == config.admin || self.roles.get(&(role, account)).unwrap_or(false)
+    }
+
+    fn set_pause_status(&mut self, kind: PauseKind, paused: bool) -> Result<(), InvariantError> {
         let caller = contract_env::caller();
         let mut config = self.config.get().unwrap_or_revert();
 
-        if caller != config.admin {
+        if !self.has_permission(caller, Role::Pauser) {
             contract_env::revert(InvariantErrorReturn::NotAdmin);
         }
 
-        config.protocol_fee = protocol_fee;
-
+        config.pause_status.set(kind, paused);
+        let pause_status = config.pause_status;
         self.config.set(config);
 
+        self.emit_change_pause_status_event(caller, pause_status);
+
         Ok(())
     }
 
-    pub fn change_fee_receiver(
+    fn set_pool_pause_status(
         &mut self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
-        fee_receiver: Address,
+        pool_key: PoolKey,
+        kind: PauseKind,
+        paused: bool,
     ) -> Result<(), InvariantError> {
-        let pool_key = unwrap_invariant_result(PoolKey::new(
-            token_0,
-            token_1,
-            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
-        ));
         let caller = contract_env::caller();
-        let config = self.config.get().unwrap_or_revert();
-        let mut pool = unwrap_invariant_result(self.pools.get(pool_key));
 
-        if caller != config.admin {
+        if !self.has_permission(caller, Role::Pauser) {
             contract_env::revert(InvariantErrorReturn::NotAdmin);
         }
 
-        pool.fee_receiver = fee_receiver;
-        unwrap_invariant_result(self.pools.update(pool_key, &pool));
+        unwrap_invariant_result(self.pools.get(pool_key));
+
+        let mut pause_status = self.pool_pause_statuses.get(&pool_key).unwrap_or_default();
+        pause_status.set(kind, paused);
+        self.pool_pause_statuses.set(&pool_key, pause_status);
+
+        self.emit_change_pool_pause_status_event(caller, pool_key, pause_status);
 
         Ok(())
     }
 
-    pub fn is_tick_initialized(
-        &self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
-        index: i32,
-    ) -> bool {
-        let key = unwrap_invariant_result(PoolKey::new(
-            token_0,
-            token_1,
-            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
-        ));
-        self.tickmap.get(index, key.fee_tier.tick_spacing, key)
+    fn check_paused(&self, pool_key: PoolKey, kind: PauseKind) -> Result<(), InvariantError> {
+        let config = self.config.get().unwrap_or_revert();
+        let pool_pause_status = self.pool_pause_statuses.get(&pool_key).unwrap_or_default();
+
+        if config.pause_status.is_paused(kind) || pool_pause_status.is_paused(kind) {
+            return Err(InvariantError::Paused);
+        }
+
+        Ok(())
     }
 
-    pub fn get_tick(
+    // block time and deadlines are both in milliseconds
+    fn check_deadline(&self, deadline_ms: u64) -> Result<(), InvariantError> {
+        if contract_env::get_block_time() > deadline_ms {
+            return Err(InvariantError::DeadlineExceeded);
+        }
+
+        Ok(())
+    }
+
+    fn get_owned_position(&self, owner: Address, id: u64) -> Result<Position, InvariantError> {
+        if self.positions.get_owner(id)? != owner {
+            return Err(InvariantError::NotPositionOwner);
+        }
+
+        self.positions.get(id)
+    }
+
+    fn get_approved_position(
         &self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
-        index: i32,
-    ) -> Result<Tick, InvariantError> {
-        let key = unwrap_invariant_result(PoolKey::new(
-            token_0,
-            token_1,
-            FeeTier::new(Percentage::new(fee), tick_spacing).unwrap(),
-        ));
-        self.ticks.get(key, index)
+        operator: Address,
+        id: u64,
+    ) -> Result<(Address, Position), InvariantError> {
+        if !self.positions.is_approved(id, operator)? {
+            return Err(InvariantError::NotPositionOwner);
+        }
+
+        Ok((self.positions.get_owner(id)?, self.positions.get(id)?))
     }
 
-    pub fn claim_fee(&mut self, index: u32) -> Result<(TokenAmount, TokenAmount), InvariantError> {
-        let caller = odra::contract_env::caller();
-        let current_timestamp = odra::contract_env::get_block_time();
-        let mut position = unwrap_invariant_result(self.positions.get(caller, index));
-        let mut lower_tick =
-            unwrap_invariant_result(self.ticks.get(position.pool_key, position.lower_tick_index));
-        let mut upper_tick =
-            unwrap_invariant_result(self.ticks.get(position.pool_key, position.upper_tick_index));
-        let mut pool = unwrap_invariant_result(self.pools.get(position.pool_key));
+    fn change_liquidity(
+        &mut self,
+        operator: Address,
+        id: u64,
+        liquidity_delta: Liquidity,
+        add: bool,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    ) -> Result<(Position, TokenAmount, TokenAmount), InvariantError> {
+        let current_timestamp = contract_env::get_block_time();
+
+        let (_, mut position) = self.get_approved_position(operator, id)?;
+
+        // withdrawing liquidity stays available while positions are paused
+        if add {
+            self.check_paused(position.pool_key, PauseKind::Positions)?;
+        }
+
+        let mut lower_tick = self
+            .ticks
+            .get(position.pool_key, position.lower_tick_index)?;
+        let mut upper_tick = self
+            .ticks
+            .get(position.pool_key, position.upper_tick_index)?;
+        let mut pool = self.pools.get(position.pool_key)?;
+
+        self.oracles.write(
+            position.pool_key,
+            current_timestamp,
+            pool.current_tick_index,
+        )?;
+
+        let (amount_x, amount_y) = position.change_liquidity(
+            &mut pool,
+            &mut lower_tick,
+            &mut upper_tick,
+            current_timestamp,
+            liquidity_delta,
+            add,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        )?;
+
+        self.positions.update(id, &position)?;
+        self.pools.update(position.pool_key, &pool)?;
+        self.ticks
+            .update(position.pool_key, position.lower_tick_index, &lower_tick)?;
+        self.ticks
+            .update(position.pool_key, position.upper_tick_index, &upper_tick)?;
+
+        Ok((position, amount_x, amount_y))
+    }
+
+    fn collect_fees(
+        &mut self,
+        id: u64,
+    ) -> Result<(Position, TokenAmount, TokenAmount), InvariantError> {
+        let current_timestamp = contract_env::get_block_time();
+
+        let mut position = self.positions.get(id)?;
+        let mut lower_tick = self
+            .ticks
+            .get(position.pool_key, position.lower_tick_index)?;
+        let mut upper_tick = self
+            .ticks
+            .get(position.pool_key, position.upper_tick_index)?;
+        let mut pool = self.pools.get(position.pool_key)?;
 
         let (x, y) = position.claim_fee(
             &mut pool,
@@ -625,53 +932,27 @@ impl Entrypoints for Invariant {
             current_timestamp,
         );
 
-        unwrap_invariant_result(self.positions.update(caller, index, &position));
-        unwrap_invariant_result(self.pools.update(position.pool_key, &pool));
-        unwrap_invariant_result(self.ticks.update(
-            position.pool_key,
-            position.lower_tick_index,
-            &lower_tick,
-        ));
-        unwrap_invariant_result(self.ticks.update(
-            position.pool_key,
-            position.upper_tick_index,
-            &upper_tick,
-        ));
-
-        if !x.get().is_zero() {
-            Erc20Ref::at(&position.pool_key.token_x).transfer(&caller, &x.get());
-        }
-
-        if !y.get().is_zero() {
-            Erc20Ref::at(&position.pool_key.token_y).transfer(&caller, &y.get());
-        }
+        self.positions.update(id, &position)?;
+        self.pools.update(position.pool_key, &pool)?;
+        self.ticks
+            .update(position.pool_key, position.lower_tick_index, &lower_tick)?;
+        self.ticks
+            .update(position.pool_key, position.upper_tick_index, &upper_tick)?;
 
-        Ok((x, y))
+        Ok((position, x, y))
     }
+
     #[allow(clippy::too_many_arguments)]
-    pub fn create_position(
+    fn open_position(
         &mut self,
-        token_0: Address,
-        token_1: Address,
-        fee: U128,
-        tick_spacing: u32,
+        owner: Address,
+        pool_key: PoolKey,
         lower_tick: i32,
         upper_tick: i32,
-        liquidity_delta: U256,
-        slippage_limit_lower: U128,
-        slippage_limit_upper: U128,
-    ) -> Result<Position, InvariantError> {
-        let pool_key = unwrap_invariant_result(PoolKey::new(
-            token_0,
-            token_1,
-            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
-        ));
-        let liquidity_delta = Liquidity::new(liquidity_delta);
-        let slippage_limit_lower = SqrtPrice::new(slippage_limit_lower);
-        let slippage_limit_upper = SqrtPrice::new(slippage_limit_upper);
-
-        let caller = contract_env::caller();
-        let contract = contract_env::self_address();
+        liquidity_delta: Liquidity,
+        slippage_limit_lower: SqrtPrice,
+        slippage_limit_upper: SqrtPrice,
+    ) -> Result<(Position, TokenAmount, TokenAmount), InvariantError> {
         let current_timestamp = contract_env::get_block_time();
         let current_block_number = contract_env::get_block_time();
 
@@ -684,7 +965,12 @@ impl Entrypoints for Invariant {
             contract_env::revert(InvariantErrorReturn::InvalidTickIndex);
         }
 
-        let mut pool = unwrap_invariant_result(self.pools.get(pool_key));
+        self.check_paused(pool_key, PauseKind::Positions)?;
+
+        let mut pool = self.pools.get(pool_key)?;
+
+        self.oracles
+            .write(pool_key, current_timestamp, pool.current_tick_index)?;
 
         let mut lower_tick = self.ticks.get(pool_key, lower_tick).unwrap_or_else(|_| {
             unwrap_invariant_result(Self::create_tick(self, pool_key, lower_tick))
@@ -694,7 +980,7 @@ impl Entrypoints for Invariant {
             unwrap_invariant_result(Self::create_tick(self, pool_key, upper_tick))
         });
 
-        let (position, x, y) = unwrap_invariant_result(Position::create(
+        let (position, x, y) = Position::create(
             &mut pool,
             pool_key,
             &mut lower_tick,
@@ -705,20 +991,17 @@ impl Entrypoints for Invariant {
             slippage_limit_upper,
             current_block_number,
             pool_key.fee_tier.tick_spacing,
-        ));
-
-        unwrap_invariant_result(self.pools.update(pool_key, &pool));
+        )?;
 
-        self.positions.add(caller, &position);
+        self.pools.update(pool_key, &pool)?;
 
-        unwrap_invariant_result(self.ticks.update(pool_key, lower_tick.index, &lower_tick));
-        unwrap_invariant_result(self.ticks.update(pool_key, upper_tick.index, &upper_tick));
+        let id = self.positions.add(owner, &position);
 
-        Erc20Ref::at(&pool_key.token_x).transfer_from(&caller, &contract, &x.get());
-        Erc20Ref::at(&pool_key.token_y).transfer_from(&caller, &contract, &y.get());
+        self.ticks.update(pool_key, lower_tick.index, &lower_tick)?;
+        self.ticks.update(pool_key, upper_tick.index, &upper_tick)?;
 
         self.emit_create_position_event(
-            caller,
+            owner,
             pool_key,
             liquidity_delta,
             lower_tick.index,
@@ -726,98 +1009,2002 @@ impl Entrypoints for Invariant {
             pool.sqrt_price,
         );
 
-        Ok(position)
+        Ok((Position { id, ..position }, x, y))
     }
 
-    pub fn transfer_position(
+    fn burn_position(
         &mut self,
-        index: u32,
-        receiver: Address,
-    ) -> Result<(), InvariantError> {
-        let caller = contract_env::caller();
+        owner: Address,
+        id: u64,
+    ) -> Result<(Position, TokenAmount, TokenAmount), InvariantError> {
+        let current_timestamp = contract_env::get_block_time();
 
-        unwrap_invariant_result(self.positions.transfer(caller, index, receiver));
+        let mut position = self.positions.get(id)?;
+        let withdrawed_liquidity = position.liquidity;
+
+        let mut lower_tick = self
+            .ticks
+            .get(position.pool_key, position.lower_tick_index)?;
+        let mut upper_tick = self
+            .ticks
+            .get(position.pool_key, position.upper_tick_index)?;
+        let pool = &mut self.pools.get(position.pool_key)?;
+
+        self.oracles.write(
+            position.pool_key,
+            current_timestamp,
+            pool.current_tick_index,
+        )?;
+
+        let (amount_x, amount_y, deinitialize_lower_tick, deinitialize_upper_tick) = position
+            .remove(
+                pool,
+                current_timestamp,
+                &mut lower_tick,
+                &mut upper_tick,
+                position.pool_key.fee_tier.tick_spacing,
+            );
+
+        self.pools.update(position.pool_key, pool)?;
+
+        if deinitialize_lower_tick {
+            self.remove_tick(position.pool_key, lower_tick)?;
+        } else {
+            self.ticks
+                .update(position.pool_key, position.lower_tick_index, &lower_tick)?;
+        }
+
+        if deinitialize_upper_tick {
+            self.remove_tick(position.pool_key, upper_tick)?;
+        } else {
+            self.ticks
+                .update(position.pool_key, position.upper_tick_index, &upper_tick)?;
+        }
+
+        self.positions.remove(id)?;
+
+        self.emit_remove_position_event(
+            owner,
+            position.pool_key,
+            withdrawed_liquidity,
+            lower_tick.index,
+            upper_tick.index,
+            pool.sqrt_price,
+        );
+
+        Ok((position, amount_x, amount_y))
+    }
+
+    fn settle_limit_orders(
+        &mut self,
+        pool_key: PoolKey,
+        x_to_y: bool,
+        crossed_ticks: &[Tick],
+    ) -> Result<(), InvariantError> {
+        let contract = contract_env::self_address();
+
+        for tick in crossed_ticks.iter() {
+            // swap has fully traversed the range of orders selling the token it buys
+            let lower_tick_index = if x_to_y {
+                tick.index
+            } else {
+                tick.index - pool_key.fee_tier.tick_spacing as i32
+            };
+
+            if let Some(mut epoch) =
+                self.limit_orders
+                    .get_active_epoch(pool_key, lower_tick_index, !x_to_y)
+            {
+                let (_, amount_x, amount_y) = self.burn_position(contract, epoch.position_id)?;
+
+                epoch.amount_x += amount_x;
+                epoch.amount_y += amount_y;
+                epoch.filled = true;
+
+                self.limit_orders.update_epoch(&epoch)?;
+                self.limit_orders
+                    .deactivate_epoch(pool_key, lower_tick_index, !x_to_y);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn calculate_position_seconds_per_liquidity_inside(
+        &self,
+        position: &Position,
+    ) -> Result<SecondsPerLiquidity, InvariantError> {
+        let current_timestamp = contract_env::get_block_time();
+
+        let lower_tick = self
+            .ticks
+            .get(position.pool_key, position.lower_tick_index)?;
+        let upper_tick = self
+            .ticks
+            .get(position.pool_key, position.upper_tick_index)?;
+        let pool = self.pools.get(position.pool_key)?;
+
+        let seconds_per_liquidity_global =
+            unwrap!(pool.get_seconds_per_liquidity_global(current_timestamp));
+
+        Ok(unwrap!(calculate_seconds_per_liquidity_inside(
+            lower_tick.index,
+            upper_tick.index,
+            pool.current_tick_index,
+            lower_tick.seconds_per_liquidity_outside,
+            upper_tick.seconds_per_liquidity_outside,
+            seconds_per_liquidity_global,
+        )))
+    }
+
+    fn emit_create_position_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        liquidity: Liquidity,
+        lower_tick: i32,
+        upper_tick: i32,
+        current_sqrt_price: SqrtPrice,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        CreatePositionEvent {
+            timestamp,
+            address,
+            pool,
+            liquidity,
+            lower_tick,
+            upper_tick,
+            current_sqrt_price,
+        }
+        .emit();
+    }
+
+    fn emit_remove_position_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        liquidity: Liquidity,
+        lower_tick: i32,
+        upper_tick: i32,
+        current_sqrt_price: SqrtPrice,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        RemovePositionEvent {
+            timestamp,
+            address,
+            pool,
+            liquidity,
+            lower_tick,
+            upper_tick,
+            current_sqrt_price,
+        }
+        .emit();
+    }
+
+    fn emit_cross_tick_event(&self, address: Address, pool: PoolKey, tick: &Tick) {
+        let timestamp = contract_env::get_block_time();
+        CrossTickEvent {
+            timestamp,
+            address,
+            pool,
+            index: tick.index,
+            sign: tick.sign,
+            liquidity_change: tick.liquidity_change,
+        }
+        .emit();
+    }
+
+    fn emit_change_pause_status_event(&self, address: Address, pause_status: PauseStatus) {
+        let timestamp = contract_env::get_block_time();
+        ChangePauseStatusEvent {
+            timestamp,
+            address,
+            pause_status,
+        }
+        .emit();
+    }
+
+    fn emit_change_pool_pause_status_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        pause_status: PauseStatus,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        ChangePoolPauseStatusEvent {
+            timestamp,
+            address,
+            pool,
+            pause_status,
+        }
+        .emit();
+    }
+
+    fn emit_admin_proposed_event(&self, admin: Address, pending_admin: Address) {
+        let timestamp = contract_env::get_block_time();
+        AdminProposedEvent {
+            timestamp,
+            admin,
+            pending_admin,
+        }
+        .emit();
+    }
+
+    fn emit_admin_changed_event(&self, previous_admin: Address, admin: Address) {
+        let timestamp = contract_env::get_block_time();
+        AdminChangedEvent {
+            timestamp,
+            previous_admin,
+            admin,
+        }
+        .emit();
+    }
+
+    fn emit_role_granted_event(&self, role: Role, account: Address, sender: Address) {
+        let timestamp = contract_env::get_block_time();
+        RoleGrantedEvent {
+            timestamp,
+            role,
+            account,
+            sender,
+        }
+        .emit();
+    }
+
+    fn emit_role_revoked_event(&self, role: Role, account: Address, sender: Address) {
+        let timestamp = contract_env::get_block_time();
+        RoleRevokedEvent {
+            timestamp,
+            role,
+            account,
+            sender,
+        }
+        .emit();
+    }
+
+    fn emit_create_pool_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        init_sqrt_price: SqrtPrice,
+        init_tick: i32,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        CreatePoolEvent {
+            timestamp,
+            address,
+            pool,
+            init_sqrt_price,
+            init_tick,
+        }
+        .emit();
+    }
+
+    fn emit_claim_fee_event(
+        &self,
+        address: Address,
+        owner: Address,
+        position_id: u64,
+        pool: PoolKey,
+        amount_x: TokenAmount,
+        amount_y: TokenAmount,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        ClaimFeeEvent {
+            timestamp,
+            address,
+            owner,
+            position_id,
+            pool,
+            amount_x,
+            amount_y,
+        }
+        .emit();
+    }
+
+    fn emit_transfer_position_event(
+        &self,
+        address: Address,
+        receiver: Address,
+        position_id: u64,
+        pool: PoolKey,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        TransferPositionEvent {
+            timestamp,
+            address,
+            receiver,
+            position_id,
+            pool,
+        }
+        .emit();
+    }
+
+    fn emit_withdraw_protocol_fee_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        amount_x: TokenAmount,
+        amount_y: TokenAmount,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        WithdrawProtocolFeeEvent {
+            timestamp,
+            address,
+            pool,
+            amount_x,
+            amount_y,
+        }
+        .emit();
+    }
+
+    fn emit_add_fee_tier_event(&self, address: Address, fee_tier: FeeTier) {
+        let timestamp = contract_env::get_block_time();
+        AddFeeTierEvent {
+            timestamp,
+            address,
+            fee_tier,
+        }
+        .emit();
+    }
+
+    fn emit_remove_fee_tier_event(&self, address: Address, fee_tier: FeeTier) {
+        let timestamp = contract_env::get_block_time();
+        RemoveFeeTierEvent {
+            timestamp,
+            address,
+            fee_tier,
+        }
+        .emit();
+    }
+
+    fn emit_change_protocol_fee_event(&self, address: Address, protocol_fee: Percentage) {
+        let timestamp = contract_env::get_block_time();
+        ChangeProtocolFeeEvent {
+            timestamp,
+            address,
+            protocol_fee,
+        }
+        .emit();
+    }
+
+    fn emit_change_fee_receiver_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        fee_receiver: Address,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        ChangeFeeReceiverEvent {
+            timestamp,
+            address,
+            pool,
+            fee_receiver,
+        }
+        .emit();
+    }
+
+    fn emit_swap_event(
+        &self,
+        address: Address,
+        pool: PoolKey,
+        result: &CalculateSwapResult,
+        x_to_y: bool,
+        by_amount_in: bool,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        SwapEvent {
+            timestamp,
+            address,
+            pool,
+            amount_in: result.amount_in,
+            amount_out: result.amount_out,
+            fee: result.fee,
+            start_sqrt_price: result.start_sqrt_price,
+            target_sqrt_price: result.target_sqrt_price,
+            x_to_y,
+            by_amount_in,
+            liquidity: result.pool.liquidity,
+            current_tick_index: result.pool.current_tick_index,
+        }
+        .emit()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn emit_flash_event(
+        &self,
+        address: Address,
+        receiver: Address,
+        pool: PoolKey,
+        amount_x: TokenAmount,
+        amount_y: TokenAmount,
+        fee_x: TokenAmount,
+        fee_y: TokenAmount,
+    ) {
+        let timestamp = contract_env::get_block_time();
+        FlashEvent {
+            timestamp,
+            address,
+            receiver,
+            pool,
+            amount_x,
+            amount_y,
+            fee_x,
+            fee_y,
+        }
+        .emit();
+    }
+}
+
+#[odra::module]
+impl Entrypoints for Invariant {
+    #[odra(init)]
+    pub fn init(&mut self, fee: U128) {
+        let protocol_fee = Percentage::new(fee);
+        let caller = contract_env::caller();
+
+        self.config.set(InvariantConfig {
+            admin: caller,
+            pending_admin: None,
+            protocol_fee,
+            pause_status: PauseStatus::default(),
+        });
+    }
+
+    pub fn add_fee_tier(&mut self, fee: U128, tick_spacing: u32) -> Result<(), InvariantError> {
+        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
+
+        let caller = contract_env::caller();
+
+        if !self.has_permission(caller, Role::FeeTierManager) {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        unwrap_invariant_result(self.fee_tiers.add(fee_tier));
+        self.emit_add_fee_tier_event(caller, fee_tier);
+
+        Ok(())
+    }
+
+    pub fn fee_tier_exist(&self, fee: U128, tick_spacing: u32) -> bool {
+        let fee_tier = FeeTier::new(Percentage::new(fee), tick_spacing).unwrap();
+        self.fee_tiers.contains(fee_tier)
+    }
+
+    pub fn remove_fee_tier(&mut self, fee: U128, tick_spacing: u32) -> Result<(), InvariantError> {
+        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
+        let caller = contract_env::caller();
+
+        if !self.has_permission(caller, Role::FeeTierManager) {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        unwrap_invariant_result(self.fee_tiers.remove(fee_tier));
+        self.emit_remove_fee_tier_event(caller, fee_tier);
+
+        Ok(())
+    }
+
+    pub fn get_fee_tiers(&self) -> Vec<FeeTier> {
+        self.fee_tiers.get_all()
+    }
+
+    pub fn get_fee_tiers_paginated(&self, offset: u32, limit: u32) -> (Vec<FeeTier>, u32) {
+        (self.fee_tiers.get(offset, limit), self.fee_tiers.count())
+    }
+
+    pub fn create_pool(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        init_sqrt_price: U128,
+        init_tick: i32,
+    ) -> Result<(), InvariantError> {
+        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
+        let init_sqrt_price = SqrtPrice::new(init_sqrt_price);
+
+        let caller = contract_env::caller();
+        let current_timestamp = odra::contract_env::get_block_time();
+        let config = self.config.get().unwrap_or_revert();
+
+        if !self.fee_tiers.contains(fee_tier) {
+            contract_env::revert(InvariantErrorReturn::FeeTierNotFound);
+        };
+
+        unwrap_invariant_result(
+            check_tick(init_tick, fee_tier.tick_spacing)
+                .map_err(|_| InvariantError::InvalidInitTick),
+        );
+
+        let pool_key = unwrap_invariant_result(PoolKey::new(token_0, token_1, fee_tier));
+
+        if self.pools.get(pool_key).is_ok() {
+            contract_env::revert(InvariantErrorReturn::PoolAlreadyExist);
+        };
+
+        let pool = unwrap_invariant_result(Pool::create(
+            init_sqrt_price,
+            init_tick,
+            current_timestamp,
+            fee_tier.tick_spacing,
+            config.admin,
+        ));
+
+        unwrap_invariant_result(self.pools.add(pool_key, &pool));
+        unwrap_invariant_result(self.oracles.initialize(pool_key, current_timestamp));
+        unwrap_invariant_result(self.pool_keys.add(pool_key));
+
+        self.emit_create_pool_event(caller, pool_key, init_sqrt_price, init_tick);
+
+        Ok(())
+    }
+
+    pub fn get_pool(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+    ) -> Result<Pool, InvariantError> {
+        let fee_tier = unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing));
+        let key: PoolKey = unwrap_invariant_result(PoolKey::new(token_0, token_1, fee_tier));
+        let pool = unwrap_invariant_result(self.pools.get(key));
+
+        Ok(pool)
+    }
+
+    pub fn get_pools(&self) -> Vec<PoolKey> {
+        self.pool_keys.get_all()
+    }
+
+    pub fn get_pools_paginated(&self, offset: u32, limit: u32) -> (Vec<PoolKey>, u32) {
+        (self.pool_keys.get(offset, limit), self.pool_keys.count())
+    }
+
+    pub fn get_protocol_fee(&self) -> Percentage {
+        let config = self.config.get().unwrap_or_revert();
+        config.protocol_fee
+    }
+
+    pub fn withdraw_protocol_fee(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        let caller = contract_env::caller();
+        let mut pool = unwrap_invariant_result(self.pools.get(pool_key));
+
+        if caller != pool.fee_receiver {
+            contract_env::revert(InvariantErrorReturn::NotFeeReceiver);
+        }
+
+        let (fee_protocol_token_x, fee_protocol_token_y) = pool.withdraw_protocol_fee(pool_key);
+
+        Erc20Ref::at(&pool_key.token_x).transfer(&pool.fee_receiver, &fee_protocol_token_x.get());
+        Erc20Ref::at(&pool_key.token_y).transfer(&pool.fee_receiver, &fee_protocol_token_y.get());
+
+        self.decrease_reserves(pool_key, fee_protocol_token_x, fee_protocol_token_y);
+        unwrap_invariant_result(self.pools.update(pool_key, &pool));
+
+        self.emit_withdraw_protocol_fee_event(
+            caller,
+            pool_key,
+            fee_protocol_token_x,
+            fee_protocol_token_y,
+        );
+
+        Ok(())
+    }
+
+    pub fn change_protocol_fee(&mut self, protocol_fee: U128) -> Result<(), InvariantError> {
+        let protocol_fee = Percentage::new(protocol_fee);
+        let caller = contract_env::caller();
+        let mut config = self.config.get().unwrap_or_revert();
+
+        if !self.has_permission(caller, Role::ProtocolFeeManager) {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        config.protocol_fee = protocol_fee;
+
+        self.config.set(config);
+
+        self.emit_change_protocol_fee_event(caller, protocol_fee);
+
+        Ok(())
+    }
+
+    pub fn change_pool_protocol_fee(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        protocol_fee: U128,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.set_pool_protocol_fee_override(pool_key, Some(Percentage::new(protocol_fee)))
+    }
+
+    pub fn reset_pool_protocol_fee(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.set_pool_protocol_fee_override(pool_key, None)
+    }
+
+    pub fn get_pool_protocol_fee(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+    ) -> Result<Percentage, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        unwrap_invariant_result(self.pools.get(pool_key));
+
+        Ok(self.get_effective_protocol_fee(pool_key))
+    }
+
+    pub fn change_fee_receiver(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        fee_receiver: Address,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let caller = contract_env::caller();
+        let mut pool = unwrap_invariant_result(self.pools.get(pool_key));
+
+        if !self.has_permission(caller, Role::FeeReceiverManager) {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        pool.fee_receiver = fee_receiver;
+        unwrap_invariant_result(self.pools.update(pool_key, &pool));
+
+        self.emit_change_fee_receiver_event(caller, pool_key, fee_receiver);
+
+        Ok(())
+    }
+
+    pub fn propose_admin(&mut self, new_admin: Address) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+        let mut config = self.config.get().unwrap_or_revert();
+
+        if caller != config.admin {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        config.pending_admin = Some(new_admin);
+        self.config.set(config);
+
+        self.emit_admin_proposed_event(caller, new_admin);
+
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+        let mut config = self.config.get().unwrap_or_revert();
+
+        if config.pending_admin != Some(caller) {
+            contract_env::revert(InvariantErrorReturn::NotPendingAdmin);
+        }
+
+        let previous_admin = config.admin;
+        config.admin = caller;
+        config.pending_admin = None;
+        self.config.set(config);
+
+        self.emit_admin_changed_event(previous_admin, caller);
+
+        Ok(())
+    }
+
+    pub fn get_admin(&self) -> Address {
+        self.config.get().unwrap_or_revert().admin
+    }
+
+    pub fn get_pending_admin(&self) -> Option<Address> {
+        self.config.get().unwrap_or_revert().pending_admin
+    }
+
+    pub fn grant_role(&mut self, role: Role, account: Address) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+        let config = self.config.get().unwrap_or_revert();
+
+        if caller != config.admin {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        self.roles.set(&(role, account), true);
+
+        self.emit_role_granted_event(role, account, caller);
+
+        Ok(())
+    }
+
+    pub fn revoke_role(&mut self, role: Role, account: Address) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+        let config = self.config.get().unwrap_or_revert();
+
+        if caller != config.admin {
+            contract_env::revert(InvariantErrorReturn::NotAdmin);
+        }
+
+        self.roles.set(&(role, account), false);
+
+        self.emit_role_revoked_event(role, account, caller);
+
+        Ok(())
+    }
+
+    pub fn has_role(&self, role: Role, account: Address) -> bool {
+        self.roles.get(&(role, account)).unwrap_or(false)
+    }
+
+    pub fn pause(&mut self, kind: PauseKind) -> Result<(), InvariantError> {
+        self.set_pause_status(kind, true)
+    }
+
+    pub fn unpause(&mut self, kind: PauseKind) -> Result<(), InvariantError> {
+        self.set_pause_status(kind, false)
+    }
+
+    pub fn get_pause_status(&self) -> PauseStatus {
+        self.config.get().unwrap_or_revert().pause_status
+    }
+
+    pub fn pause_pool(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        kind: PauseKind,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.set_pool_pause_status(pool_key, kind, true)
+    }
+
+    pub fn unpause_pool(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        kind: PauseKind,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.set_pool_pause_status(pool_key, kind, false)
+    }
+
+    pub fn get_pool_pause_status(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+    ) -> PauseStatus {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.pool_pause_statuses.get(&pool_key).unwrap_or_default()
+    }
+
+    pub fn increase_observation_cardinality(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        cardinality: u32,
+    ) -> Result<(), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        unwrap_invariant_result(self.oracles.grow(pool_key, cardinality));
+
+        Ok(())
+    }
+
+    pub fn observe(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        milliseconds_agos: Vec<u64>,
+    ) -> Result<ObserveResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let current_timestamp = contract_env::get_block_time();
+
+        if milliseconds_agos
+            .windows(2)
+            .any(|window| window[0] <= window[1])
+        {
+            contract_env::revert(InvariantErrorReturn::InvalidObservationWindow);
+        }
+
+        let pool = unwrap_invariant_result(self.pools.get(pool_key));
+
+        let tick_cumulatives = unwrap_invariant_result(self.oracles.observe(
+            pool_key,
+            current_timestamp,
+            milliseconds_agos.clone(),
+            pool.current_tick_index,
+        ));
+
+        let mut average_ticks: Vec<i32> = vec![];
+        let mut average_sqrt_prices: Vec<SqrtPrice> = vec![];
+
+        for i in 1..tick_cumulatives.len() {
+            let time_delta = (milliseconds_agos[i - 1] - milliseconds_agos[i]) as i64;
+            // rounds towards negative infinity
+            let average_tick =
+                (tick_cumulatives[i] - tick_cumulatives[i - 1]).div_euclid(time_delta) as i32;
+
+            average_ticks.push(average_tick);
+            average_sqrt_prices.push(unwrap_invariant_result(
+                calculate_sqrt_price(average_tick).map_err(|_| InvariantError::InvalidTickIndex),
+            ));
+        }
+
+        Ok(ObserveResult {
+            tick_cumulatives,
+            average_ticks,
+            average_sqrt_prices,
+        })
+    }
+
+    pub fn is_tick_initialized(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        index: i32,
+    ) -> bool {
+        let key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        self.tickmap.get(index, key.fee_tier.tick_spacing, key)
+    }
+
+    pub fn get_tick(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        index: i32,
+    ) -> Result<Tick, InvariantError> {
+        let key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            FeeTier::new(Percentage::new(fee), tick_spacing).unwrap(),
+        ));
+        self.ticks.get(key, index)
+    }
+
+    pub fn get_liquidity_ticks(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        tick_indexes: Vec<i32>,
+    ) -> Result<Vec<Tick>, InvariantError> {
+        let key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        if tick_indexes.len() > LIQUIDITY_TICK_LIMIT as usize {
+            return Err(InvariantError::TooManyTicks);
+        }
+
+        tick_indexes
+            .into_iter()
+            .map(|index| self.ticks.get(key, index))
+            .collect()
+    }
+
+    pub fn get_tickmap(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        x_to_y: bool,
+    ) -> Result<(Vec<i32>, Option<i32>), InvariantError> {
+        let key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        check_ticks(lower_tick, upper_tick, tick_spacing)
+            .map_err(|_| InvariantError::InvalidTickIndexOrTickSpacing)?;
+
+        Ok(self
+            .tickmap
+            .get_initialized_in_range(lower_tick, upper_tick, tick_spacing, key, x_to_y))
+    }
+
+    pub fn claim_fee(&mut self, id: u64) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let caller = odra::contract_env::caller();
+
+        // a staked position is held by the contract, but its fees still belong to the staker
+        let owner = match self.incentives.get_stake(id) {
+            Ok(stake) if stake.owner == caller => caller,
+            _ => unwrap_invariant_result(self.get_approved_position(caller, id)).0,
+        };
+
+        let (position, x, y) = unwrap_invariant_result(self.collect_fees(id));
+
+        if !x.get().is_zero() {
+            Erc20Ref::at(&position.pool_key.token_x).transfer(&owner, &x.get());
+        }
+
+        if !y.get().is_zero() {
+            Erc20Ref::at(&position.pool_key.token_y).transfer(&owner, &y.get());
+        }
+
+        self.decrease_reserves(position.pool_key, x, y);
+
+        self.emit_claim_fee_event(caller, owner, id, position.pool_key, x, y);
+
+        Ok((x, y))
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_position(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+    ) -> Result<Position, InvariantError> {
+        self.create_position_with_owner(
+            token_0,
+            token_1,
+            fee,
+            tick_spacing,
+            lower_tick,
+            upper_tick,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+            contract_env::caller(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_position_with_owner(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+        owner: Address,
+    ) -> Result<Position, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let liquidity_delta = Liquidity::new(liquidity_delta);
+        let slippage_limit_lower = SqrtPrice::new(slippage_limit_lower);
+        let slippage_limit_upper = SqrtPrice::new(slippage_limit_upper);
+
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        let (position, x, y) = unwrap_invariant_result(self.open_position(
+            owner,
+            pool_key,
+            lower_tick,
+            upper_tick,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        ));
+
+        Erc20Ref::at(&pool_key.token_x).transfer_from(&caller, &contract, &x.get());
+        Erc20Ref::at(&pool_key.token_y).transfer_from(&caller, &contract, &y.get());
+
+        self.increase_reserves(pool_key, x, y);
+
+        Ok(position)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_position_by_amounts(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        amount_x_desired: U256,
+        amount_y_desired: U256,
+        amount_x_min: U256,
+        amount_y_min: U256,
+    ) -> Result<Position, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let min_sqrt_price = SqrtPrice::new(U128::from(MIN_SQRT_PRICE));
+        let max_sqrt_price = SqrtPrice::new(U128::from(MAX_SQRT_PRICE));
+
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        if lower_tick >= upper_tick || check_ticks(lower_tick, upper_tick, tick_spacing).is_err() {
+            contract_env::revert(InvariantErrorReturn::InvalidTickIndex);
+        }
+
+        let pool = unwrap_invariant_result(self.pools.get(pool_key));
+        let liquidity_delta = unwrap_invariant_result(
+            get_max_liquidity(
+                TokenAmount::new(amount_x_desired),
+                TokenAmount::new(amount_y_desired),
+                lower_tick,
+                upper_tick,
+                pool.current_tick_index,
+                pool.sqrt_price,
+            )
+            .map_err(|_| InvariantError::LiquidityOverflow),
+        );
+
+        let (position, x, y) = unwrap_invariant_result(self.open_position(
+            caller,
+            pool_key,
+            lower_tick,
+            upper_tick,
+            liquidity_delta,
+            min_sqrt_price,
+            max_sqrt_price,
+        ));
+
+        if x.get() > amount_x_desired || y.get() > amount_y_desired {
+            contract_env::revert(InvariantErrorReturn::AmountOverMaximumAmountIn);
+        }
+
+        if x.get() < amount_x_min || y.get() < amount_y_min {
+            contract_env::revert(InvariantErrorReturn::AmountBelowMinimum);
+        }
+
+        Erc20Ref::at(&pool_key.token_x).transfer_from(&caller, &contract, &x.get());
+        Erc20Ref::at(&pool_key.token_y).transfer_from(&caller, &contract, &y.get());
+
+        self.increase_reserves(pool_key, x, y);
+
+        Ok(position)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_position_with_deadline(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        upper_tick: i32,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+        deadline_ms: u64,
+    ) -> Result<Position, InvariantError> {
+        unwrap_invariant_result(self.check_deadline(deadline_ms));
+
+        self.create_position(
+            token_0,
+            token_1,
+            fee,
+            tick_spacing,
+            lower_tick,
+            upper_tick,
+            liquidity_delta,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        )
+    }
+
+    pub fn increase_liquidity(
+        &mut self,
+        id: u64,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+    ) -> Result<Position, InvariantError> {
+        let liquidity_delta = Liquidity::new(liquidity_delta);
+        let slippage_limit_lower = SqrtPrice::new(slippage_limit_lower);
+        let slippage_limit_upper = SqrtPrice::new(slippage_limit_upper);
+
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        let (position, x, y) = unwrap_invariant_result(self.change_liquidity(
+            caller,
+            id,
+            liquidity_delta,
+            true,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        ));
+
+        Erc20Ref::at(&position.pool_key.token_x).transfer_from(&caller, &contract, &x.get());
+        Erc20Ref::at(&position.pool_key.token_y).transfer_from(&caller, &contract, &y.get());
+
+        self.increase_reserves(position.pool_key, x, y);
+
+        Ok(position)
+    }
+
+    pub fn decrease_liquidity(
+        &mut self,
+        id: u64,
+        liquidity_delta: U256,
+        slippage_limit_lower: U128,
+        slippage_limit_upper: U128,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let liquidity_delta = Liquidity::new(liquidity_delta);
+        let slippage_limit_lower = SqrtPrice::new(slippage_limit_lower);
+        let slippage_limit_upper = SqrtPrice::new(slippage_limit_upper);
+
+        let caller = contract_env::caller();
+
+        let (position, amount_x, amount_y) = unwrap_invariant_result(self.change_liquidity(
+            caller,
+            id,
+            liquidity_delta,
+            false,
+            slippage_limit_lower,
+            slippage_limit_upper,
+        ));
+
+        let owner = unwrap_invariant_result(self.positions.get_owner(id));
+
+        Erc20Ref::at(&position.pool_key.token_x).transfer(&owner, &amount_x.get());
+        Erc20Ref::at(&position.pool_key.token_y).transfer(&owner, &amount_y.get());
+
+        self.decrease_reserves(position.pool_key, amount_x, amount_y);
+
+        Ok((amount_x, amount_y))
+    }
+
+    pub fn transfer_position(&mut self, id: u64, receiver: Address) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+
+        let position = unwrap_invariant_result(self.get_owned_position(caller, id));
+        unwrap_invariant_result(self.positions.transfer(id, receiver));
+
+        self.emit_transfer_position_event(caller, receiver, id, position.pool_key);
+
+        Ok(())
+    }
+
+    pub fn approve_position(&mut self, id: u64, operator: Address) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+
+        unwrap_invariant_result(self.get_owned_position(caller, id));
+        unwrap_invariant_result(self.positions.approve(id, operator));
+
+        Ok(())
+    }
+
+    pub fn revoke_position(&mut self, id: u64) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+
+        unwrap_invariant_result(self.get_owned_position(caller, id));
+        unwrap_invariant_result(self.positions.revoke(id));
+
+        Ok(())
+    }
+
+    pub fn approve_operator(&mut self, operator: Address) {
+        let caller = contract_env::caller();
+
+        self.positions.set_operator(caller, operator, true);
+    }
+
+    pub fn revoke_operator(&mut self, operator: Address) {
+        let caller = contract_env::caller();
+
+        self.positions.set_operator(caller, operator, false);
+    }
+
+    pub fn is_approved(&self, id: u64, operator: Address) -> Result<bool, InvariantError> {
+        self.positions.is_approved(id, operator)
+    }
+
+    pub fn remove_position(
+        &mut self,
+        id: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let caller = contract_env::caller();
+
+        let (owner, _) = unwrap_invariant_result(self.get_approved_position(caller, id));
+
+        let (position, amount_x, amount_y) = unwrap_invariant_result(self.burn_position(owner, id));
+
+        Erc20Ref::at(&position.pool_key.token_x).transfer(&owner, &amount_x.get());
+        Erc20Ref::at(&position.pool_key.token_y).transfer(&owner, &amount_y.get());
+
+        self.decrease_reserves(position.pool_key, amount_x, amount_y);
+
+        Ok((amount_x, amount_y))
+    }
+
+    pub fn remove_position_with_deadline(
+        &mut self,
+        id: u64,
+        deadline_ms: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        unwrap_invariant_result(self.check_deadline(deadline_ms));
+
+        self.remove_position(id)
+    }
+
+    pub fn remove_position_with_min_amounts(
+        &mut self,
+        id: u64,
+        amount_x_min: U256,
+        amount_y_min: U256,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let (amount_x, amount_y) = self.remove_position(id)?;
+
+        if amount_x.get() < amount_x_min || amount_y.get() < amount_y_min {
+            contract_env::revert(InvariantErrorReturn::AmountBelowMinimum);
+        }
+
+        Ok((amount_x, amount_y))
+    }
+
+    pub fn get_position(&mut self, id: u64) -> Result<Position, InvariantError> {
+        self.positions.get(id)
+    }
+
+    pub fn get_position_owner(&mut self, id: u64) -> Result<Address, InvariantError> {
+        self.positions.get_owner(id)
+    }
+
+    pub fn get_position_ids(&mut self, owner: Address) -> Vec<u64> {
+        self.positions.get_ids(owner)
+    }
+
+    pub fn get_all_positions(&mut self, owner: Address) -> Vec<Position> {
+        self.positions.get_all(owner)
+    }
+
+    pub fn get_positions_paginated(
+        &self,
+        owner: Address,
+        offset: u32,
+        limit: u32,
+    ) -> (Vec<Position>, u32) {
+        (
+            self.positions.get_paginated(owner, offset, limit),
+            self.positions.get_length(owner),
+        )
+    }
+
+    pub fn get_seconds_per_liquidity_inside(
+        &self,
+        id: u64,
+    ) -> Result<SecondsPerLiquidity, InvariantError> {
+        let position = unwrap_invariant_result(self.positions.get(id));
+
+        Ok(unwrap_invariant_result(
+            self.calculate_position_seconds_per_liquidity_inside(&position),
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_incentive(
+        &mut self,
+        reward_token: Address,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        reward: U256,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Result<u64, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let reward = TokenAmount::new(reward);
+
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+        let current_timestamp = contract_env::get_block_time();
+
+        unwrap_invariant_result(self.pools.get(pool_key));
+
+        if reward.is_zero() {
+            contract_env::revert(InvariantErrorReturn::AmountIsZero);
+        }
+
+        if start_timestamp < current_timestamp || start_timestamp >= end_timestamp {
+            contract_env::revert(InvariantErrorReturn::InvalidIncentiveTime);
+        }
+
+        let id = self.incentives.add(&Incentive {
+            id: 0,
+            reward_token,
+            pool_key,
+            start_timestamp,
+            end_timestamp,
+            refundee: caller,
+            total_reward_unclaimed: reward,
+            total_seconds_claimed: U256::from(0),
+            number_of_stakes: 0,
+        });
+
+        Erc20Ref::at(&reward_token).transfer_from(&caller, &contract, &reward.get());
+
+        Ok(id)
+    }
+
+    pub fn stake(&mut self, incentive_id: u64, position_id: u64) -> Result<(), InvariantError> {
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+        let current_timestamp = contract_env::get_block_time();
+
+        let position = unwrap_invariant_result(self.get_owned_position(caller, position_id));
+        let mut incentive = unwrap_invariant_result(self.incentives.get(incentive_id));
+
+        if !incentive.is_active(current_timestamp) {
+            contract_env::revert(InvariantErrorReturn::IncentiveNotActive);
+        }
+
+        if position.pool_key != incentive.pool_key {
+            contract_env::revert(InvariantErrorReturn::IncentivePoolMismatch);
+        }
+
+        if position.liquidity.is_zero() {
+            contract_env::revert(InvariantErrorReturn::ZeroLiquidity);
+        }
+
+        let seconds_per_liquidity_inside = unwrap_invariant_result(
+            self.calculate_position_seconds_per_liquidity_inside(&position),
+        );
+
+        unwrap_invariant_result(self.incentives.add_stake(
+            position_id,
+            &Stake {
+                incentive_id,
+                owner: caller,
+                liquidity: position.liquidity,
+                seconds_per_liquidity_inside_initial: seconds_per_liquidity_inside,
+            },
+        ));
+
+        incentive.number_of_stakes += 1;
+        unwrap_invariant_result(self.incentives.update(&incentive));
+
+        // the contract holds the position, so its liquidity stays constant while staked
+        unwrap_invariant_result(self.positions.transfer(position_id, contract));
 
         Ok(())
     }
 
-    pub fn remove_position(
+    pub fn unstake(&mut self, position_id: u64) -> Result<TokenAmount, InvariantError> {
+        let caller = contract_env::caller();
+        let current_timestamp = contract_env::get_block_time();
+
+        let stake = unwrap_invariant_result(self.incentives.get_stake(position_id));
+        let mut incentive = unwrap_invariant_result(self.incentives.get(stake.incentive_id));
+
+        // anyone can unstake after the end, so the leftover can be refunded
+        if caller != stake.owner && !incentive.is_ended(current_timestamp) {
+            contract_env::revert(InvariantErrorReturn::NotPositionOwner);
+        }
+
+        let position = unwrap_invariant_result(self.positions.get(position_id));
+        let seconds_per_liquidity_inside = unwrap_invariant_result(
+            self.calculate_position_seconds_per_liquidity_inside(&position),
+        );
+
+        let (reward, seconds_inside) = unwrap!(incentive.calculate_reward(
+            &stake,
+            seconds_per_liquidity_inside,
+            current_timestamp
+        ));
+
+        incentive.total_reward_unclaimed -= reward;
+        incentive.total_seconds_claimed += seconds_inside;
+        incentive.number_of_stakes -= 1;
+        unwrap_invariant_result(self.incentives.update(&incentive));
+
+        self.incentives
+            .add_reward(stake.owner, incentive.reward_token, reward);
+
+        unwrap_invariant_result(self.incentives.remove_stake(position_id));
+        unwrap_invariant_result(self.positions.transfer(position_id, stake.owner));
+
+        Ok(reward)
+    }
+
+    pub fn claim_reward(&mut self, reward_token: Address) -> Result<TokenAmount, InvariantError> {
+        let caller = contract_env::caller();
+
+        let reward = self.incentives.take_reward(caller, reward_token);
+
+        if !reward.is_zero() {
+            Erc20Ref::at(&reward_token).transfer(&caller, &reward.get());
+        }
+
+        Ok(reward)
+    }
+
+    pub fn refund_incentive(&mut self, incentive_id: u64) -> Result<TokenAmount, InvariantError> {
+        let current_timestamp = contract_env::get_block_time();
+
+        let mut incentive = unwrap_invariant_result(self.incentives.get(incentive_id));
+
+        if !incentive.is_ended(current_timestamp) || incentive.number_of_stakes > 0 {
+            contract_env::revert(InvariantErrorReturn::IncentiveNotEnded);
+        }
+
+        let refund = incentive.total_reward_unclaimed;
+        incentive.total_reward_unclaimed = TokenAmount::new(U256::from(0));
+        unwrap_invariant_result(self.incentives.update(&incentive));
+
+        if !refund.is_zero() {
+            Erc20Ref::at(&incentive.reward_token).transfer(&incentive.refundee, &refund.get());
+        }
+
+        Ok(refund)
+    }
+
+    pub fn get_incentive(&self, incentive_id: u64) -> Result<Incentive, InvariantError> {
+        self.incentives.get(incentive_id)
+    }
+
+    pub fn get_stake(&self, position_id: u64) -> Result<Stake, InvariantError> {
+        self.incentives.get_stake(position_id)
+    }
+
+    pub fn get_reward(&self, owner: Address, reward_token: Address) -> TokenAmount {
+        self.incentives.get_reward(owner, reward_token)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_limit_order(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        lower_tick: i32,
+        x_to_y: bool,
+        liquidity_delta: U256,
+    ) -> Result<LimitOrder, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let liquidity_delta = Liquidity::new(liquidity_delta);
+        let min_sqrt_price = SqrtPrice::new(U128::from(MIN_SQRT_PRICE));
+        let max_sqrt_price = SqrtPrice::new(U128::from(MAX_SQRT_PRICE));
+
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        let pool = unwrap_invariant_result(self.pools.get(pool_key));
+        let upper_tick = lower_tick + tick_spacing as i32;
+
+        // order has to hold only the token it sells
+        let is_out_of_range = if x_to_y {
+            lower_tick > pool.current_tick_index
+        } else {
+            upper_tick <= pool.current_tick_index
+        };
+
+        if !is_out_of_range {
+            contract_env::revert(InvariantErrorReturn::InvalidLimitOrderTick);
+        }
+
+        let (epoch_id, x, y) = match self
+            .limit_orders
+            .get_active_epoch(pool_key, lower_tick, x_to_y)
+        {
+            Some(epoch) => {
+                let (_, x, y) = unwrap_invariant_result(self.change_liquidity(
+                    contract,
+                    epoch.position_id,
+                    liquidity_delta,
+                    true,
+                    min_sqrt_price,
+                    max_sqrt_price,
+                ));
+
+                unwrap_invariant_result(self.limit_orders.update_epoch(&LimitOrderEpoch {
+                    liquidity: epoch.liquidity + liquidity_delta,
+                    ..epoch
+                }));
+
+                (epoch.id, x, y)
+            }
+            None => {
+                let (position, x, y) = unwrap_invariant_result(self.open_position(
+                    contract,
+                    pool_key,
+                    lower_tick,
+                    upper_tick,
+                    liquidity_delta,
+                    min_sqrt_price,
+                    max_sqrt_price,
+                ));
+
+                let epoch_id = self.limit_orders.add_epoch(&LimitOrderEpoch {
+                    id: 0,
+                    pool_key,
+                    lower_tick_index: lower_tick,
+                    x_to_y,
+                    position_id: position.id,
+                    liquidity: liquidity_delta,
+                    filled: false,
+                    amount_x: TokenAmount::new(U256::from(0)),
+                    amount_y: TokenAmount::new(U256::from(0)),
+                });
+
+                (epoch_id, x, y)
+            }
+        };
+
+        Erc20Ref::at(&pool_key.token_x).transfer_from(&caller, &contract, &x.get());
+        Erc20Ref::at(&pool_key.token_y).transfer_from(&caller, &contract, &y.get());
+
+        self.increase_reserves(pool_key, x, y);
+
+        let order = LimitOrder {
+            id: 0,
+            owner: caller,
+            epoch_id,
+            liquidity: liquidity_delta,
+        };
+        let id = self.limit_orders.add_order(&order);
+
+        Ok(LimitOrder { id, ..order })
+    }
+
+    pub fn cancel_limit_order(
+        &mut self,
+        order_id: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        let order = unwrap_invariant_result(self.limit_orders.get_order(order_id));
+
+        if order.owner != caller {
+            contract_env::revert(InvariantErrorReturn::NotPositionOwner);
+        }
+
+        let mut epoch = unwrap_invariant_result(self.limit_orders.get_epoch(order.epoch_id));
+
+        if epoch.filled {
+            contract_env::revert(InvariantErrorReturn::LimitOrderFilled);
+        }
+
+        // fees earned so far are split between orders like the proceeds of a fill
+        let (_, fee_x, fee_y) = unwrap_invariant_result(self.collect_fees(epoch.position_id));
+        epoch.amount_x += fee_x;
+        epoch.amount_y += fee_y;
+
+        let (mut amount_x, mut amount_y) = if order.liquidity == epoch.liquidity {
+            let (_, x, y) =
+                unwrap_invariant_result(self.burn_position(contract, epoch.position_id));
+            self.limit_orders.deactivate_epoch(
+                epoch.pool_key,
+                epoch.lower_tick_index,
+                epoch.x_to_y,
+            );
+            (x, y)
+        } else {
+            let (_, x, y) = unwrap_invariant_result(self.change_liquidity(
+                contract,
+                epoch.position_id,
+                order.liquidity,
+                false,
+                SqrtPrice::new(U128::from(MIN_SQRT_PRICE)),
+                SqrtPrice::new(U128::from(MAX_SQRT_PRICE)),
+            ));
+            (x, y)
+        };
+
+        let (share_x, share_y) = unwrap!(epoch.withdraw_share(order.liquidity));
+        amount_x += share_x;
+        amount_y += share_y;
+
+        unwrap_invariant_result(self.limit_orders.update_epoch(&epoch));
+        unwrap_invariant_result(self.limit_orders.remove_order(order_id));
+
+        Erc20Ref::at(&epoch.pool_key.token_x).transfer(&caller, &amount_x.get());
+        Erc20Ref::at(&epoch.pool_key.token_y).transfer(&caller, &amount_y.get());
+
+        self.decrease_reserves(epoch.pool_key, amount_x, amount_y);
+
+        Ok((amount_x, amount_y))
+    }
+
+    pub fn claim_limit_order(
+        &mut self,
+        order_id: u64,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let caller = contract_env::caller();
+
+        let order = unwrap_invariant_result(self.limit_orders.get_order(order_id));
+
+        if order.owner != caller {
+            contract_env::revert(InvariantErrorReturn::NotPositionOwner);
+        }
+
+        let mut epoch = unwrap_invariant_result(self.limit_orders.get_epoch(order.epoch_id));
+
+        if !epoch.filled {
+            contract_env::revert(InvariantErrorReturn::LimitOrderNotFilled);
+        }
+
+        let (x, y) = unwrap!(epoch.withdraw_share(order.liquidity));
+
+        unwrap_invariant_result(self.limit_orders.update_epoch(&epoch));
+        unwrap_invariant_result(self.limit_orders.remove_order(order_id));
+
+        if !x.get().is_zero() {
+            Erc20Ref::at(&epoch.pool_key.token_x).transfer(&caller, &x.get());
+        }
+
+        if !y.get().is_zero() {
+            Erc20Ref::at(&epoch.pool_key.token_y).transfer(&caller, &y.get());
+        }
+
+        self.decrease_reserves(epoch.pool_key, x, y);
+
+        Ok((x, y))
+    }
+
+    pub fn get_limit_order(&self, order_id: u64) -> Result<LimitOrder, InvariantError> {
+        self.limit_orders.get_order(order_id)
+    }
+
+    pub fn get_limit_order_epoch(&self, epoch_id: u64) -> Result<LimitOrderEpoch, InvariantError> {
+        self.limit_orders.get_epoch(epoch_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn quote(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+    ) -> Result<QuoteResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            FeeTier::new(Percentage::new(fee), tick_spacing).unwrap(),
+        ));
+        self.quote_swap(
+            pool_key,
+            x_to_y,
+            TokenAmount::new(amount),
+            by_amount_in,
+            SqrtPrice::new(sqrt_price_limit),
+            false,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn quote_with_partial_fill(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+    ) -> Result<QuoteResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.quote_swap(
+            pool_key,
+            x_to_y,
+            TokenAmount::new(amount),
+            by_amount_in,
+            SqrtPrice::new(sqrt_price_limit),
+            true,
+        )
+    }
+
+    pub fn quote_swap_to_price(
+        &self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        target_sqrt_price: U128,
+        max_amount_in: U256,
+    ) -> Result<QuoteResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let target_sqrt_price = SqrtPrice::new(target_sqrt_price);
+        let pool = unwrap_invariant_result(self.pools.get(pool_key));
+
+        let quote_result = self.quote_swap(
+            pool_key,
+            target_sqrt_price < pool.sqrt_price,
+            TokenAmount::new(max_amount_in),
+            true,
+            target_sqrt_price,
+            true,
+        )?;
+
+        if quote_result.target_sqrt_price != target_sqrt_price {
+            contract_env::revert(InvariantErrorReturn::AmountOverMaximumAmountIn);
+        }
+
+        Ok(quote_result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        self.swap_and_transfer(
+            pool_key,
+            x_to_y,
+            TokenAmount::new(amount),
+            by_amount_in,
+            SqrtPrice::new(sqrt_price_limit),
+            false,
+            contract_env::caller(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_with_recipient(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        recipient: Address,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+
+        self.swap_and_transfer(
+            pool_key,
+            x_to_y,
+            TokenAmount::new(amount),
+            by_amount_in,
+            SqrtPrice::new(sqrt_price_limit),
+            false,
+            recipient,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_with_deadline(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        deadline_ms: u64,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        self.swap_with_recipient_and_deadline(
+            token_0,
+            token_1,
+            fee,
+            tick_spacing,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            contract_env::caller(),
+            deadline_ms,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_with_recipient_and_deadline(
         &mut self,
-        index: u32,
-    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
-        let caller = contract_env::caller();
-        let current_timestamp = contract_env::get_block_time();
-
-        let mut position = unwrap_invariant_result(self.positions.get(caller, index));
-        let withdrawed_liquidity = position.liquidity;
-
-        let mut lower_tick =
-            unwrap_invariant_result(self.ticks.get(position.pool_key, position.lower_tick_index));
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        recipient: Address,
+        deadline_ms: u64,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        unwrap_invariant_result(self.check_deadline(deadline_ms));
 
-        let mut upper_tick =
-            unwrap_invariant_result(self.ticks.get(position.pool_key, position.upper_tick_index));
+        self.swap_with_recipient(
+            token_0,
+            token_1,
+            fee,
+            tick_spacing,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+            recipient,
+        )
+    }
 
-        let pool = &mut unwrap_invariant_result(self.pools.get(position.pool_key));
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_with_slippage(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        x_to_y: bool,
+        amount: U256,
+        by_amount_in: bool,
+        sqrt_price_limit: U128,
+        expected_amount: U256,
+        slippage: U128,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        let expected_amount = TokenAmount::new(expected_amount);
+        let slippage = Percentage::new(slippage);
 
-        let (amount_x, amount_y, deinitialize_lower_tick, deinitialize_upper_tick) = position
-            .remove(
-                pool,
-                current_timestamp,
-                &mut lower_tick,
-                &mut upper_tick,
-                position.pool_key.fee_tier.tick_spacing,
-            );
+        let calculate_swap_result = self.swap(
+            token_0,
+            token_1,
+            fee,
+            tick_spacing,
+            x_to_y,
+            amount,
+            by_amount_in,
+            sqrt_price_limit,
+        )?;
 
-        unwrap_invariant_result(self.pools.update(position.pool_key, pool));
+        if by_amount_in {
+            let min_amount_out = calculate_min_amount_out(expected_amount, slippage);
 
-        if deinitialize_lower_tick {
-            unwrap_invariant_result(self.remove_tick(position.pool_key, lower_tick));
+            if calculate_swap_result.amount_out < min_amount_out {
+                contract_env::revert(InvariantErrorReturn::AmountUnderMinimumAmountOut);
+            }
         } else {
-            unwrap_invariant_result(self.ticks.update(
-                position.pool_key,
-                position.lower_tick_index,
-                &lower_tick,
-            ));
-        }
+            let max_amount_in = calculate_max_amount_in(expected_amount, slippage);
 
-        if deinitialize_upper_tick {
-            unwrap_invariant_result(self.remove_tick(position.pool_key, upper_tick));
-        } else {
-            unwrap_invariant_result(self.ticks.update(
-                position.pool_key,
-                position.upper_tick_index,
-                &upper_tick,
-            ));
+            if calculate_swap_result.amount_in > max_amount_in {
+                contract_env::revert(InvariantErrorReturn::AmountOverMaximumAmountIn);
+            }
         }
 
-        unwrap_invariant_result(self.positions.remove(caller, index));
-
-        Erc20Ref::at(&position.pool_key.token_x).transfer(&caller, &amount_x.get());
-        Erc20Ref::at(&position.pool_key.token_y).transfer(&caller, &amount_y.get());
-
-        self.emit_remove_position_event(
-            caller,
-            position.pool_key,
-            withdrawed_liquidity,
-            lower_tick.index,
-            upper_tick.index,
-            pool.sqrt_price,
-        );
-
-        Ok((amount_x, amount_y))
-    }
-
-    pub fn get_position(&mut self, owner: Address, index: u32) -> Result<Position, InvariantError> {
-        self.positions.get(owner, index)
-    }
-
-    pub fn get_all_positions(&mut self, owner: Address) -> Vec<Position> {
-        self.positions.get_all(owner)
+        Ok(calculate_swap_result)
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub fn quote(
-        &self,
+    pub fn swap_with_partial_fill(
+        &mut self,
         token_0: Address,
         token_1: Address,
         fee: U128,
@@ -826,33 +3013,61 @@ impl Entrypoints for Invariant {
         amount: U256,
         by_amount_in: bool,
         sqrt_price_limit: U128,
-    ) -> Result<QuoteResult, InvariantError> {
+    ) -> Result<CalculateSwapResult, InvariantError> {
         let pool_key = unwrap_invariant_result(PoolKey::new(
             token_0,
             token_1,
-            FeeTier::new(Percentage::new(fee), tick_spacing).unwrap(),
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
         ));
-        let amount = TokenAmount::new(amount);
-        let sqrt_price_limit = SqrtPrice::new(sqrt_price_limit);
 
-        let calculate_swap_result = unwrap_invariant_result(self.calculate_swap(
+        self.swap_and_transfer(
             pool_key,
             x_to_y,
-            amount,
+            TokenAmount::new(amount),
             by_amount_in,
-            sqrt_price_limit,
+            SqrtPrice::new(sqrt_price_limit),
+            true,
+            contract_env::caller(),
+        )
+    }
+
+    pub fn swap_to_price(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        target_sqrt_price: U128,
+        max_amount_in: U256,
+    ) -> Result<CalculateSwapResult, InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
         ));
+        let target_sqrt_price = SqrtPrice::new(target_sqrt_price);
+        let pool = unwrap_invariant_result(self.pools.get(pool_key));
 
-        Ok(QuoteResult {
-            amount_in: calculate_swap_result.amount_in,
-            amount_out: calculate_swap_result.amount_out,
-            target_sqrt_price: calculate_swap_result.pool.sqrt_price,
-            ticks: calculate_swap_result.ticks,
-        })
+        // the swap stops at the target, so any input it does not need is left with the caller
+        let calculate_swap_result = self.swap_and_transfer(
+            pool_key,
+            target_sqrt_price < pool.sqrt_price,
+            TokenAmount::new(max_amount_in),
+            true,
+            target_sqrt_price,
+            true,
+            contract_env::caller(),
+        )?;
+
+        if calculate_swap_result.pool.sqrt_price != target_sqrt_price {
+            contract_env::revert(InvariantErrorReturn::AmountOverMaximumAmountIn);
+        }
+
+        Ok(calculate_swap_result)
     }
 
     #[allow(clippy::too_many_arguments)]
-    pub fn swap(
+    pub fn flash_swap(
         &mut self,
         token_0: Address,
         token_1: Address,
@@ -862,6 +3077,7 @@ impl Entrypoints for Invariant {
         amount: U256,
         by_amount_in: bool,
         sqrt_price_limit: U128,
+        data: Vec<u8>,
     ) -> Result<CalculateSwapResult, InvariantError> {
         let pool_key = unwrap_invariant_result(PoolKey::new(
             token_0,
@@ -874,69 +3090,181 @@ impl Entrypoints for Invariant {
         let caller = contract_env::caller();
         let contract = contract_env::self_address();
 
-        let calculate_swap_result = unwrap_invariant_result(self.calculate_swap(
+        let calculate_swap_result = unwrap_invariant_result(self.execute_swap(
+            caller,
             pool_key,
             x_to_y,
             amount,
             by_amount_in,
             sqrt_price_limit,
+            false,
         ));
 
-        let mut crossed_tick_indexes: Vec<i32> = vec![];
+        let zero = TokenAmount::new(U256::from(0));
+        let (token_in, token_out, amounts_in, amounts_out) = if x_to_y {
+            (
+                pool_key.token_x,
+                pool_key.token_y,
+                (calculate_swap_result.amount_in, zero),
+                (zero, calculate_swap_result.amount_out),
+            )
+        } else {
+            (
+                pool_key.token_y,
+                pool_key.token_x,
+                (zero, calculate_swap_result.amount_in),
+                (calculate_swap_result.amount_out, zero),
+            )
+        };
 
-        for tick in calculate_swap_result.ticks.iter() {
-            unwrap_invariant_result(self.ticks.update(pool_key, tick.index, tick));
-            crossed_tick_indexes.push(tick.index);
-        }
+        // the pool state is already committed by the swap, the output leaves the reserves before
+        // the callback while the input is only counted once it has been collected
+        self.decrease_reserves(pool_key, amounts_out.0, amounts_out.1);
 
-        if !crossed_tick_indexes.is_empty() {
-            self.emit_cross_tick_event(caller, pool_key, crossed_tick_indexes);
-        }
+        Erc20Ref::at(&token_out).transfer(&caller, &calculate_swap_result.amount_out.get());
 
-        unwrap_invariant_result(self.pools.update(pool_key, &calculate_swap_result.pool));
+        SwapCallbackRef::at(&caller).invariant_swap_callback(
+            x_to_y,
+            calculate_swap_result.amount_in.get(),
+            calculate_swap_result.amount_out.get(),
+            data,
+        );
 
-        if x_to_y {
-            Erc20Ref::at(&pool_key.token_x).transfer_from(
-                &caller,
-                &contract,
-                &calculate_swap_result.amount_in.get(),
-            );
-            Erc20Ref::at(&pool_key.token_y)
-                .transfer(&caller, &calculate_swap_result.amount_out.get());
-        } else {
-            Erc20Ref::at(&pool_key.token_y).transfer_from(
-                &caller,
-                &contract,
-                &calculate_swap_result.amount_in.get(),
-            );
-            Erc20Ref::at(&pool_key.token_x)
-                .transfer(&caller, &calculate_swap_result.amount_out.get());
-        };
+        Erc20Ref::at(&token_in).transfer_from(
+            &caller,
+            &contract,
+            &calculate_swap_result.amount_in.get(),
+        );
+
+        self.increase_reserves(pool_key, amounts_in.0, amounts_in.1);
 
         self.emit_swap_event(
             caller,
             pool_key,
-            calculate_swap_result.amount_in,
-            calculate_swap_result.amount_out,
-            calculate_swap_result.fee,
-            calculate_swap_result.start_sqrt_price,
-            calculate_swap_result.target_sqrt_price,
+            &calculate_swap_result,
             x_to_y,
+            by_amount_in,
         );
 
         Ok(calculate_swap_result)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn flash(
+        &mut self,
+        token_0: Address,
+        token_1: Address,
+        fee: U128,
+        tick_spacing: u32,
+        amount_x: U256,
+        amount_y: U256,
+        receiver: Address,
+        data: Vec<u8>,
+    ) -> Result<(TokenAmount, TokenAmount), InvariantError> {
+        let pool_key = unwrap_invariant_result(PoolKey::new(
+            token_0,
+            token_1,
+            unwrap_invariant_result(FeeTier::new(Percentage::new(fee), tick_spacing)),
+        ));
+        let amount_x = TokenAmount::new(amount_x);
+        let amount_y = TokenAmount::new(amount_y);
+
+        let caller = contract_env::caller();
+        let contract = contract_env::self_address();
+
+        if amount_x.is_zero() && amount_y.is_zero() {
+            contract_env::revert(InvariantErrorReturn::AmountIsZero);
+        }
+
+        unwrap_invariant_result(self.check_paused(pool_key, PauseKind::Swaps));
+        unwrap_invariant_result(self.pools.get(pool_key));
+
+        // other pools of the same tokens can't be drained through this one, and the lent amount
+        // stays out of the reserves until it is repaid so nested loans can't borrow it again
+        self.decrease_reserves(pool_key, amount_x, amount_y);
+
+        let fee_x = amount_x.big_mul_up(pool_key.fee_tier.fee);
+        let fee_y = amount_y.big_mul_up(pool_key.fee_tier.fee);
+
+        let mut token_x = Erc20Ref::at(&pool_key.token_x);
+        let mut token_y = Erc20Ref::at(&pool_key.token_y);
+
+        if !amount_x.is_zero() {
+            token_x.transfer(&receiver, &amount_x.get());
+        }
+
+        if !amount_y.is_zero() {
+            token_y.transfer(&receiver, &amount_y.get());
+        }
+
+        FlashCallbackRef::at(&receiver).invariant_flash_callback(
+            amount_x.get(),
+            amount_y.get(),
+            fee_x.get(),
+            fee_y.get(),
+            data,
+        );
+
+        // repayment is pulled so tokens moved by reentrant calls can't be counted as returned
+        if !amount_x.is_zero() {
+            token_x.transfer_from(&receiver, &contract, &(amount_x + fee_x).get());
+        }
+
+        if !amount_y.is_zero() {
+            token_y.transfer_from(&receiver, &contract, &(amount_y + fee_y).get());
+        }
+
+        // pool could have been changed by the receiver
+        let mut pool = unwrap_invariant_result(self.pools.get(pool_key));
+        let protocol_fee = self.get_effective_protocol_fee(pool_key);
+
+        // without active liquidity there is nobody to distribute the fee to
+        if pool.liquidity.is_zero() {
+            pool.fee_protocol_token_x += fee_x;
+            pool.fee_protocol_token_y += fee_y;
+        } else {
+            unwrap!(pool.add_fee(fee_x, true, protocol_fee));
+            unwrap!(pool.add_fee(fee_y, false, protocol_fee));
+        }
+
+        self.increase_reserves(pool_key, amount_x + fee_x, amount_y + fee_y);
+        unwrap_invariant_result(self.pools.update(pool_key, &pool));
+
+        self.emit_flash_event(caller, receiver, pool_key, amount_x, amount_y, fee_x, fee_y);
+
+        Ok((fee_x, fee_y))
+    }
+
+    pub fn multicall(&mut self, calls: Vec<Call>) -> Result<Vec<CallResult>, InvariantError> {
+        let mut results: Vec<CallResult> = vec![];
+
+        // a failing call reverts the whole deploy, so the calls are applied all or none
+        for call in calls {
+            results.push(unwrap_invariant_result(self.execute_call(call)));
+        }
+
+        Ok(results)
+    }
+
     pub fn quote_route(
         &mut self,
         amount_in: U256,
         swaps: Vec<SwapHop>,
     ) -> Result<TokenAmount, InvariantError> {
+        Ok(self.quote_route_with_hops(amount_in, swaps)?.amount_out)
+    }
+
+    pub fn quote_route_with_hops(
+        &mut self,
+        amount_in: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<QuoteRouteResult, InvariantError> {
         let amount_in = TokenAmount::new(amount_in);
 
-        let amount_out = unwrap_invariant_result(self.route(false, amount_in, swaps));
+        let quote_route_result =
+            unwrap_invariant_result(self.route(false, amount_in, swaps, contract_env::caller()));
 
-        Ok(amount_out)
+        Ok(quote_route_result)
     }
 
     pub fn swap_route(
@@ -945,12 +3273,30 @@ impl Entrypoints for Invariant {
         expected_amount_out: U256,
         slippage: U128,
         swaps: Vec<SwapHop>,
+    ) -> Result<(), InvariantError> {
+        self.swap_route_with_recipient(
+            amount_in,
+            expected_amount_out,
+            slippage,
+            swaps,
+            contract_env::caller(),
+        )
+    }
+
+    pub fn swap_route_with_recipient(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+        recipient: Address,
     ) -> Result<(), InvariantError> {
         let amount_in = TokenAmount::new(amount_in);
         let expected_amount_out = TokenAmount::new(expected_amount_out);
         let slippage = Percentage::new(slippage);
 
-        let amount_out = unwrap_invariant_result(self.route(true, amount_in, swaps));
+        let amount_out =
+            unwrap_invariant_result(self.route(true, amount_in, swaps, recipient)).amount_out;
 
         let min_amount_out = calculate_min_amount_out(expected_amount_out, slippage);
 
@@ -960,4 +3306,89 @@ impl Entrypoints for Invariant {
 
         Ok(())
     }
+
+    pub fn quote_route_exact_out(
+        &mut self,
+        amount_out: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<QuoteRouteResult, InvariantError> {
+        let amount_out = TokenAmount::new(amount_out);
+
+        let quote_route_result = unwrap_invariant_result(self.route_exact_out(
+            false,
+            amount_out,
+            TokenAmount::max_instance(),
+            swaps,
+            contract_env::caller(),
+        ));
+
+        Ok(quote_route_result)
+    }
+
+    pub fn swap_route_with_deadline(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+        deadline_ms: u64,
+    ) -> Result<(), InvariantError> {
+        self.swap_route_with_recipient_and_deadline(
+            amount_in,
+            expected_amount_out,
+            slippage,
+            swaps,
+            contract_env::caller(),
+            deadline_ms,
+        )
+    }
+
+    pub fn swap_route_with_recipient_and_deadline(
+        &mut self,
+        amount_in: U256,
+        expected_amount_out: U256,
+        slippage: U128,
+        swaps: Vec<SwapHop>,
+        recipient: Address,
+        deadline_ms: u64,
+    ) -> Result<(), InvariantError> {
+        unwrap_invariant_result(self.check_deadline(deadline_ms));
+
+        self.swap_route_with_recipient(amount_in, expected_amount_out, slippage, swaps, recipient)
+    }
+
+    pub fn swap_route_exact_out(
+        &mut self,
+        amount_out: U256,
+        max_amount_in: U256,
+        swaps: Vec<SwapHop>,
+    ) -> Result<(), InvariantError> {
+        self.swap_route_exact_out_with_recipient(
+            amount_out,
+            max_amount_in,
+            swaps,
+            contract_env::caller(),
+        )
+    }
+
+    pub fn swap_route_exact_out_with_recipient(
+        &mut self,
+        amount_out: U256,
+        max_amount_in: U256,
+        swaps: Vec<SwapHop>,
+        recipient: Address,
+    ) -> Result<(), InvariantError> {
+        let amount_out = TokenAmount::new(amount_out);
+        let max_amount_in = TokenAmount::new(max_amount_in);
+
+        unwrap_invariant_result(self.route_exact_out(
+            true,
+            amount_out,
+            max_amount_in,
+            swaps,
+            recipient,
+        ));
+
+        Ok(())
+    }
 }
@@ -377,6 +377,13 @@ pub fn calculate_min_amount_out(
     expected_amount_out.big_mul_up(Percentage::from_integer(1u8) - slippage)
 }
 
+pub fn calculate_max_amount_in(
+    expected_amount_in: TokenAmount,
+    slippage: Percentage,
+) -> TokenAmount {
+    expected_amount_in.big_mul(Percentage::from_integer(1u8) + slippage)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -457,6 +464,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_calculate_max_amount_in() {
+        // 0% slippage
+        {
+            let expected_amount_in = TokenAmount::new(U256::from(100));
+            let slippage = Percentage::from_integer(0);
+            let result = calculate_max_amount_in(expected_amount_in, slippage);
+            assert_eq!(result, TokenAmount::new(U256::from(100)));
+        }
+        // 0.9% slippage
+        {
+            let expected_amount_in = TokenAmount::new(U256::from(123));
+            let slippage = Percentage::from_scale(9, 3);
+            let result = calculate_max_amount_in(expected_amount_in, slippage);
+            assert_eq!(result, TokenAmount::new(U256::from(124)));
+        }
+        // 1% slippage
+        {
+            let expected_amount_in = TokenAmount::new(U256::from(100));
+            let slippage = Percentage::from_scale(1, 2);
+            let result = calculate_max_amount_in(expected_amount_in, slippage);
+            assert_eq!(result, TokenAmount::new(U256::from(101)));
+        }
+        // 100% slippage
+        {
+            let expected_amount_in = TokenAmount::new(U256::from(100));
+            let slippage = Percentage::from_integer(1);
+            let result = calculate_max_amount_in(expected_amount_in, slippage);
+            assert_eq!(result, TokenAmount::new(U256::from(200)));
+        }
+    }
+
     #[test]
     fn test_domain_calculate_min_amount_out() {
         let min_amount = TokenAmount::new(U256::from(0));
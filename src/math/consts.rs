@@ -6,3 +6,9 @@ pub const MIN_SQRT_PRICE: u128 = 15258932000000000000;
 
 pub const TICK_SEARCH_RANGE: i32 = 256;
 pub const CHUNK_SIZE: i32 = 64;
+
+pub const MILLISECONDS_PER_SECOND: u64 = 1000;
+
+pub const LIQUIDITY_TICK_LIMIT: u32 = 256;
+pub const TICKMAP_CHUNK_LIMIT: u16 = 64;
+pub const MAX_ORACLE_CARDINALITY: u32 = 65535;